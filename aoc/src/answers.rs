@@ -0,0 +1,150 @@
+use anyhow::{anyhow, bail, Context, Result};
+use common::{read_input, Answer, INPUT_FILE};
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+/*
+Registry of known-correct answers, read from `answers.toml` at the workspace root.
+
+Answers are keyed by day, then by the input file inside the day's directory, then by part:
+
+    [day24.ninthprime]
+    part1 = 45121475050728
+    part2 = "gqp,hsw,jmh,mwk,qgd,z10,z18,z33"
+*/
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// One recorded answer for a part of a day run against a specific input file.
+#[derive(Debug)]
+pub struct Expected {
+    pub day: u8,
+    pub part: u8,
+    /// Input file name, relative to the day's directory.
+    pub input: String,
+    pub answer: Answer,
+}
+
+impl Expected {
+    pub fn input_path(&self) -> PathBuf {
+        common::day_dir(self.day).join(&self.input)
+    }
+}
+
+pub fn default_path() -> PathBuf {
+    common::workspace_dir().join(ANSWERS_FILE)
+}
+
+pub fn load(path: &Path) -> Result<Vec<Expected>> {
+    let contents = read_input(path)?;
+    parse(&contents).with_context(|| format!("Invalid answers file {}", path.display()))
+}
+
+/// The workspace's answers, or none when it has no answers file.
+pub fn load_known() -> Result<Vec<Expected>> {
+    let path = default_path();
+    if path.exists() {
+        load(&path)
+    } else {
+        Ok(Vec::new())
+    }
+}
+
+/// The known answer of `part` of `day` for the input at `path`. Answers are recorded by file name
+/// in the day's directory, and a cached input is the day's input.txt.
+pub fn lookup<'a>(known: &'a [Expected], day: u8, part: u8, path: &Path) -> Option<&'a Answer> {
+    let name = path
+        .strip_prefix(common::day_dir(day))
+        .map_or(INPUT_FILE.into(), |name| name.to_string_lossy());
+    known
+        .iter()
+        .find(|e| e.day == day && e.part == part && e.input == name)
+        .map(|e| &e.answer)
+}
+
+fn parse(contents: &str) -> Result<Vec<Expected>> {
+    let table: Table = contents.parse()?;
+    let mut expected = Vec::new();
+
+    for (day_key, inputs) in &table {
+        let day = day_key
+            .strip_prefix("day")
+            .and_then(|n| n.parse::<u8>().ok())
+            .ok_or_else(|| anyhow!("Expected a key like `day05`, found `{}`", day_key))?;
+        let Value::Table(inputs) = inputs else {
+            bail!("`{}` must be a table of input files", day_key);
+        };
+
+        for (input, parts) in inputs {
+            let Value::Table(parts) = parts else {
+                bail!("`{}.{}` must be a table of parts", day_key, input);
+            };
+
+            for (part_key, value) in parts {
+                let part = match part_key.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    _ => bail!("Unknown part `{}` in `{}.{}`", part_key, day_key, input),
+                };
+                let answer = match value {
+                    Value::Integer(n) => Answer::try_from(*n)?,
+                    Value::String(s) => Answer::Text(s.clone()),
+                    _ => bail!("`{}.{}.{}` must be a number or a string", day_key, input, part_key),
+                };
+                expected.push(Expected {
+                    day,
+                    part,
+                    input: input.clone(),
+                    answer,
+                });
+            }
+        }
+    }
+
+    expected.sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+    Ok(expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let contents = r#"
+            [day24.ninthprime]
+            part2 = "gqp,hsw"
+            part1 = 42
+
+            [day01."input.txt"]
+            part1 = 11
+        "#;
+        let expected = parse(contents).unwrap();
+        let keys: Vec<_> = expected
+            .iter()
+            .map(|e| (e.day, e.part, e.input.as_str(), e.answer.to_string()))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                (1, 1, "input.txt", "11".to_string()),
+                (24, 1, "ninthprime", "42".to_string()),
+                (24, 2, "ninthprime", "gqp,hsw".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lookup() {
+        let known = parse("[day01.\"input.txt\"]\npart1 = 11\n[day01.\"example.txt\"]\npart1 = 3\n").unwrap();
+        let lookup = |part, path: &Path| lookup(&known, 1, part, path).map(|a| a.to_string());
+        assert_eq!(lookup(1, &common::day_dir(1).join("example.txt")).as_deref(), Some("3"));
+        assert_eq!(lookup(1, &common::cached_input_path(common::YEAR, 1)).as_deref(), Some("11"));
+        assert_eq!(lookup(2, &common::day_dir(1).join("input.txt")), None);
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_part() {
+        assert!(parse("[day01.\"input.txt\"]\npart3 = 1\n").is_err());
+    }
+}
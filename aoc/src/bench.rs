@@ -0,0 +1,178 @@
+use anyhow::{Context, Result};
+use common::bench::Measurement;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/*
+`aoc bench`: JSON reports of `common::bench` measurements and comparison against a baseline.
+
+A part regresses when its median total time, or its peak heap where both reports counted
+allocations, grows by more than the threshold, in percent, over the baseline report.
+*/
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Report {
+    pub results: Vec<Measurement>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&contents).with_context(|| format!("Invalid benchmark report {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+    /// Relative change of the median total time, in percent.
+    pub change: f64,
+    /// Peak heap of the baseline and now, when both reports have it.
+    pub peak_bytes: Option<(u64, u64)>,
+    /// Relative change of the peak heap, in percent.
+    pub peak_change: Option<f64>,
+    pub regressed: bool,
+}
+
+/// Relative change from `before` to `now`, in percent.
+fn percent_change(before: u64, now: u64) -> f64 {
+    if before == 0 {
+        0.0
+    } else {
+        (now as f64 / before as f64 - 1.0) * 100.0
+    }
+}
+
+/// Compares every part present in both reports.
+pub fn compare(current: &Report, baseline: &Report, threshold: f64) -> Vec<Comparison> {
+    current
+        .results
+        .iter()
+        .filter_map(|now| {
+            let before = baseline
+                .results
+                .iter()
+                .find(|b| (b.day, b.part) == (now.day, now.part))?;
+            let (baseline_ns, current_ns) = (before.total.median_ns, now.total.median_ns);
+            let change = percent_change(baseline_ns, current_ns);
+            let peak_bytes = before.memory.zip(now.memory).map(|(b, n)| (b.peak_bytes(), n.peak_bytes()));
+            let peak_change = peak_bytes.map(|(before, now)| percent_change(before, now));
+            Some(Comparison {
+                day: now.day,
+                part: now.part,
+                baseline_ns,
+                current_ns,
+                change,
+                peak_bytes,
+                peak_change,
+                regressed: change > threshold || peak_change.is_some_and(|change| change > threshold),
+            })
+        })
+        .collect()
+}
+
+/// "12.3 ms" style rendering of nanoseconds.
+pub fn format_ns(ns: u64) -> String {
+    crate::readme::format_runtime(std::time::Duration::from_nanos(ns))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::alloc::AllocStats;
+    use common::bench::{Memory, Stats};
+
+    fn measurement(day: u8, part: u8, median_ns: u64) -> Measurement {
+        let stats = Stats {
+            median_ns,
+            p95_ns: median_ns,
+            min_ns: median_ns,
+            max_ns: median_ns,
+        };
+        Measurement {
+            day,
+            part,
+            answer: "0".to_string(),
+            iterations: 1,
+            parse: stats.clone(),
+            solve: stats.clone(),
+            total: stats,
+            memory: None,
+        }
+    }
+
+    fn with_peak(measurement: Measurement, peak_bytes: u64) -> Measurement {
+        let stats = AllocStats {
+            allocations: 1,
+            bytes: peak_bytes,
+            peak_bytes,
+        };
+        Measurement {
+            memory: Some(Memory {
+                parse: AllocStats::default(),
+                solve: stats,
+            }),
+            ..measurement
+        }
+    }
+
+    #[test]
+    fn test_compare_flags_regressions_above_threshold() {
+        let baseline = Report {
+            results: vec![measurement(6, 2, 1000), measurement(9, 1, 1000), measurement(20, 2, 1000)],
+        };
+        let current = Report {
+            results: vec![measurement(6, 2, 1050), measurement(9, 1, 1200), measurement(21, 1, 5)],
+        };
+        let comparisons = compare(&current, &baseline, 10.0);
+        let flagged: Vec<_> = comparisons.iter().map(|c| (c.day, c.part, c.regressed)).collect();
+        assert_eq!(flagged, vec![(6, 2, false), (9, 1, true)]);
+        assert!((comparisons[1].change - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_compare_flags_memory_regressions() {
+        let baseline = Report {
+            results: vec![with_peak(measurement(22, 2, 1000), 1000), measurement(6, 2, 1000)],
+        };
+        let current = Report {
+            results: vec![with_peak(measurement(22, 2, 1000), 1500), with_peak(measurement(6, 2, 1000), 9)],
+        };
+        let comparisons = compare(&current, &baseline, 10.0);
+        assert_eq!(comparisons[0].peak_bytes, Some((1000, 1500)));
+        assert!(comparisons[0].regressed);
+        assert_eq!(comparisons[1].peak_change, None);
+        assert!(!comparisons[1].regressed);
+    }
+
+    #[test]
+    fn test_report_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bench/latest.json");
+        let report = Report {
+            results: vec![measurement(1, 2, 42)],
+        };
+        report.save(&path).unwrap();
+        let loaded = Report::load(&path).unwrap();
+        assert_eq!(loaded.results[0].total, report.results[0].total);
+        assert!(!fs::read_to_string(&path).unwrap().contains("memory"));
+
+        let report = Report {
+            results: vec![with_peak(measurement(1, 2, 42), 4096)],
+        };
+        report.save(&path).unwrap();
+        assert_eq!(Report::load(&path).unwrap().results[0].memory, report.results[0].memory);
+    }
+}
@@ -0,0 +1,219 @@
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/*
+HTTP client for the puzzle server.
+
+Requests carry the session cookie of a logged-in account and are throttled through a timestamp
+file, so consecutive invocations of the runner also keep their distance.
+*/
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_FILE: &str = ".session";
+/// Minimum time between two requests to the server.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = "AdventofCode2024 aoc runner (ureq)";
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    throttle_file: PathBuf,
+    min_interval: Duration,
+}
+
+impl Client {
+    /// Client for `base_url`, remembering its last request in `throttle_file`.
+    pub fn new(base_url: &str, session: String, throttle_file: PathBuf) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            throttle_file,
+            min_interval: MIN_INTERVAL,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Puzzle input of `day` in `year`.
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        let path = format!("/{}/day/{}/input", year, day);
+        self.throttle()?;
+        let request = self.request("GET", &path);
+        match request.call() {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(404, _)) => {
+                bail!("The input of {} day {} is not available yet", year, day)
+            }
+            Err(ureq::Error::Status(400 | 500, _)) => {
+                bail!("The server rejected the session token, log in again and update it")
+            }
+            Err(err) => Err(err).with_context(|| format!("GET {}{}", self.base_url, path)),
+        }
+    }
+
+    /// Posts `answer` for `part` and returns the text of the server's reply.
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<String> {
+        let path = format!("/{}/day/{}/answer", year, day);
+        self.throttle()?;
+        let request = self.request("POST", &path);
+        match request.send_form(&[("level", &part.to_string()), ("answer", answer)]) {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(400 | 500, _)) => {
+                bail!("The server rejected the session token, log in again and update it")
+            }
+            Err(err) => Err(err).with_context(|| format!("POST {}{}", self.base_url, path)),
+        }
+    }
+
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        self.agent
+            .request(method, &format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    /// Sleeps until `min_interval` has passed since the previous request, then records this one.
+    fn throttle(&self) -> Result<()> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        let last = fs::read_to_string(&self.throttle_file)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(Duration::from_millis);
+        if let Some(wait) = last.and_then(|last| (last + self.min_interval).checked_sub(now)) {
+            thread::sleep(wait);
+        }
+
+        if let Some(dir) = self.throttle_file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        fs::write(&self.throttle_file, now.as_millis().to_string())?;
+        Ok(())
+    }
+}
+
+/// Session cookie from `AOC_SESSION`, or from the git-ignored `.session` file in `root`.
+pub fn session_token(root: &Path) -> Result<String> {
+    if let Ok(token) = std::env::var("AOC_SESSION") {
+        return Ok(token.trim().to_string());
+    }
+    let path = root.join(SESSION_FILE);
+    let token = fs::read_to_string(&path).with_context(|| {
+        format!("Set AOC_SESSION or put the session cookie into {}", path.display())
+    })?;
+    Ok(token.trim().to_string())
+}
+
+/// Downloads the input of `day` into `cache_file` unless it is already there.
+/// Returns whether the server was contacted.
+pub fn fetch_input(client: &Client, year: u16, day: u8, cache_file: &Path, force: bool) -> Result<bool> {
+    if cache_file.exists() && !force {
+        return Ok(false);
+    }
+    let input = client.input(year, day)?;
+    if let Some(dir) = cache_file.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(cache_file, input).with_context(|| format!("Failed to write {}", cache_file.display()))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::time::Instant;
+
+    /// A request seen by the stand-in server.
+    pub struct Seen {
+        pub method: String,
+        pub url: String,
+        pub cookie: Option<String>,
+        pub body: String,
+    }
+
+    /// Serves the given `(status, body)` responses in order on a local port and reports every
+    /// request it saw. Returns the base URL.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<Seen>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, response) in responses {
+                let mut request = server.recv().unwrap();
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string());
+                sender
+                    .send(Seen {
+                        method: request.method().to_string(),
+                        url: request.url().to_string(),
+                        cookie,
+                        body,
+                    })
+                    .unwrap();
+                request
+                    .respond(tiny_http::Response::from_string(response).with_status_code(status))
+                    .unwrap();
+            }
+        });
+        (base_url, receiver)
+    }
+
+    pub fn client(base_url: &str, dir: &Path) -> Client {
+        Client::new(base_url, "abc123".to_string(), dir.join("last-request"))
+            .with_min_interval(Duration::ZERO)
+    }
+
+    #[test]
+    fn test_fetch_input_caches_download() {
+        let dir = tempfile::tempdir().unwrap();
+        let (base_url, seen) = serve(vec![(200, "1 2\n3 4\n")]);
+        let client = client(&base_url, dir.path());
+        let cache_file = dir.path().join("2024/day01.txt");
+
+        assert!(fetch_input(&client, 2024, 1, &cache_file, false).unwrap());
+        assert_eq!(fs::read_to_string(&cache_file).unwrap(), "1 2\n3 4\n");
+        let request = seen.recv().unwrap();
+        assert_eq!((request.method.as_str(), request.url.as_str()), ("GET", "/2024/day/1/input"));
+        assert_eq!(request.cookie.as_deref(), Some("session=abc123"));
+
+        // The server only answers once, so a second download would fail.
+        assert!(!fetch_input(&client, 2024, 1, &cache_file, false).unwrap());
+    }
+
+    #[test]
+    fn test_fetch_input_reports_missing_day() {
+        let dir = tempfile::tempdir().unwrap();
+        let (base_url, _seen) = serve(vec![(404, "Not Found")]);
+        let cache_file = dir.path().join("2024/day25.txt");
+        let err = fetch_input(&client(&base_url, dir.path()), 2024, 25, &cache_file, false).unwrap_err();
+        assert!(err.to_string().contains("not available yet"));
+        assert!(!cache_file.exists());
+    }
+
+    #[test]
+    fn test_requests_are_throttled() {
+        let dir = tempfile::tempdir().unwrap();
+        let (base_url, _seen) = serve(vec![(200, "a"), (200, "b")]);
+        let client = client(&base_url, dir.path()).with_min_interval(Duration::from_millis(300));
+
+        let start = Instant::now();
+        client.input(2024, 1).unwrap();
+        client.input(2024, 2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+    }
+}
@@ -0,0 +1,148 @@
+use common::generator::Generator;
+use common::Solver;
+
+/// A day of the calendar together with the solvers for its parts, in order.
+pub struct Day {
+    pub day: u8,
+    pub parts: &'static [Solver],
+    /// Synthetic inputs for `aoc gen`, once the day has a generator.
+    pub generate: Option<Generator>,
+}
+
+impl Day {
+    pub fn solver(&self, part: u8) -> Option<Solver> {
+        self.parts.get(usize::from(part).checked_sub(1)?).copied()
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        parts: &[day01::part1, day01::part2],
+        generate: Some(day01::generator::generate),
+    },
+    Day {
+        day: 2,
+        parts: &[day02::part1, day02::part2],
+        generate: Some(day02::generator::generate),
+    },
+    Day {
+        day: 3,
+        parts: &[day03::part1, day03::part2],
+        generate: Some(day03::generator::generate),
+    },
+    Day {
+        day: 4,
+        parts: &[day04::part1, day04::part2],
+        generate: Some(day04::generator::generate),
+    },
+    Day {
+        day: 5,
+        parts: &[day05::part1, day05::part2],
+        generate: Some(day05::generator::generate),
+    },
+    Day {
+        day: 6,
+        parts: &[day06::part1, day06::part2],
+        generate: Some(day06::generator::generate),
+    },
+    Day {
+        day: 7,
+        parts: &[day07::part1, day07::part2],
+        generate: Some(day07::generator::generate),
+    },
+    Day {
+        day: 8,
+        parts: &[day08::part1, day08::part2],
+        generate: Some(day08::generator::generate),
+    },
+    Day {
+        day: 9,
+        parts: &[day09::part1, day09::part2],
+        generate: Some(day09::generator::generate),
+    },
+    Day {
+        day: 10,
+        parts: &[day10::part1, day10::part2],
+        generate: Some(day10::generator::generate),
+    },
+    Day {
+        day: 11,
+        parts: &[day11::part1, day11::part2],
+        generate: Some(day11::generator::generate),
+    },
+    Day {
+        day: 12,
+        parts: &[day12::part1, day12::part2],
+        generate: Some(day12::generator::generate),
+    },
+    Day {
+        day: 13,
+        parts: &[day13::part1, day13::part2],
+        generate: Some(day13::generator::generate),
+    },
+    Day {
+        day: 14,
+        parts: &[day14::part1, day14::part2],
+        generate: Some(day14::generator::generate),
+    },
+    Day {
+        day: 15,
+        parts: &[day15::part1, day15::part2],
+        generate: Some(day15::generator::generate),
+    },
+    Day {
+        day: 16,
+        parts: &[day16::part1, day16::part2],
+        generate: Some(day16::generator::generate),
+    },
+    Day {
+        day: 17,
+        parts: &[day17::part1, day17::part2],
+        generate: Some(day17::generator::generate),
+    },
+    Day {
+        day: 18,
+        parts: &[day18::part1, day18::part2],
+        generate: Some(day18::generator::generate),
+    },
+    Day {
+        day: 19,
+        parts: &[day19::part1, day19::part2],
+        generate: Some(day19::generator::generate),
+    },
+    Day {
+        day: 20,
+        parts: &[day20::part1, day20::part2],
+        generate: Some(day20::generator::generate),
+    },
+    Day {
+        day: 21,
+        parts: &[day21::part1, day21::part2],
+        generate: Some(day21::generator::generate),
+    },
+    Day {
+        day: 22,
+        parts: &[day22::part1, day22::part2],
+        generate: Some(day22::generator::generate),
+    },
+    Day {
+        day: 23,
+        parts: &[day23::part1, day23::part2],
+        generate: Some(day23::generator::generate),
+    },
+    Day {
+        day: 24,
+        parts: &[day24::part1, day24::part2],
+        generate: Some(day24::generator::generate),
+    },
+    Day {
+        day: 25,
+        parts: &[day25::part1],
+        generate: Some(day25::generator::generate),
+    },
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
@@ -0,0 +1,633 @@
+use anyhow::{bail, Context, Result};
+use clap::{Args, Parser, Subcommand};
+use common::generator::GenOptions;
+use common::{read_input, Answer, Format, InputSource, Record, TraceArgs, EXAMPLE_FILE};
+use report::{PartResult, Status, Summary};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+mod answers;
+mod bench;
+mod client;
+mod days;
+mod readme;
+mod report;
+mod runner;
+mod scaffold;
+mod submit;
+mod watch;
+
+/// Counts the allocations of the solutions for `bench`, at some cost to their speed.
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: common::alloc::CountingAllocator = common::alloc::CountingAllocator;
+
+/*
+Single entry point for all Advent of Code 2024 solutions.
+*/
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    #[command(flatten)]
+    trace: TraceArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution of one day, or of every day with --all
+    Run(RunArgs),
+    /// Re-run every solution with a recorded answer and compare the results
+    Verify(VerifyArgs),
+    /// Generate the crate for a new day and register it with the workspace
+    New(NewArgs),
+    /// Download the puzzle input of a day into the input cache
+    Fetch(FetchArgs),
+    /// Solve a part and submit its answer, unless it is known to be wrong
+    Submit(SubmitArgs),
+    /// Regenerate the leaderboard section of README.md from the stats files
+    Readme(ReadmeArgs),
+    /// Time the parse and solve phases of solutions over repeated runs
+    Bench(BenchArgs),
+    /// Print a synthetic input for a day, or solve it and check the planted answers with --check
+    Gen(GenArgs),
+    /// Rebuild a day and re-run it on its examples and input whenever its files change
+    Watch(WatchArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day of the puzzle (1-25)
+    #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Part of the puzzle; both parts are run when omitted
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the puzzle input from this file, or from standard input for `-`, instead of the
+    /// day's input (default: $AOC_INPUT)
+    #[arg(long, conflicts_with_all = ["example", "all"])]
+    input: Option<PathBuf>,
+    /// Use the day's example.txt instead of input.txt
+    #[arg(long)]
+    example: bool,
+    /// Print each answer as prose or as a JSON line with its parse and solve times
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+    /// Run every part of every day, each in its own process, several at a time
+    #[arg(long, conflicts_with_all = ["day", "part"])]
+    all: bool,
+    /// Parts to run at once with --all [default: the number of CPUs]
+    #[arg(long, conflicts_with_all = ["day", "part"], value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+    /// Seconds after which a part run with --all is killed
+    #[arg(long, conflicts_with_all = ["day", "part"], default_value = "60", value_parser = parse_timeout)]
+    timeout: Duration,
+    /// Also write the summary of --all to this file, as HTML for .html and Markdown otherwise
+    #[arg(long, conflicts_with_all = ["day", "part"])]
+    report: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Answers file to check against instead of the workspace's answers.toml
+    #[arg(long)]
+    answers: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day of the puzzle (1-25)
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Year of the puzzle, used for the link to its description
+    #[arg(long, default_value_t = common::YEAR)]
+    year: u16,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day of the puzzle (1-25)
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Year of the puzzle
+    #[arg(long, default_value_t = common::YEAR)]
+    year: u16,
+    /// Puzzle server to download from
+    #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+    /// Download again even if the input is already cached
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day of the puzzle (1-25)
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Part of the puzzle
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Solve this input file, or standard input for `-`, instead of the day's input
+    /// (default: $AOC_INPUT)
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Puzzle server to submit to
+    #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+#[derive(Args)]
+struct ReadmeArgs {
+    /// Personal stats instead of the workspace's stats/personal.json
+    #[arg(long)]
+    stats: Option<PathBuf>,
+    /// Private leaderboard JSON to report the standing from
+    #[arg(long)]
+    leaderboard: Option<PathBuf>,
+    /// Leaderboard member name or id; the leaderboard's owner when omitted
+    #[arg(long, requires = "leaderboard")]
+    member: Option<String>,
+    /// Leave the runtime columns empty instead of running every solution
+    #[arg(long)]
+    no_runtimes: bool,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only benchmark this day
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Only benchmark this part
+    #[arg(requires = "day", value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Use the days' example.txt instead of their input
+    #[arg(long)]
+    example: bool,
+    /// Untimed runs before measuring
+    #[arg(long, default_value_t = 3)]
+    warmup: u32,
+    /// Timed runs per part
+    #[arg(long, default_value_t = 20)]
+    iterations: u32,
+    /// Write the measurements to this JSON file
+    #[arg(long)]
+    json: Option<PathBuf>,
+    /// Compare against a report previously written with --json
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+    /// Growth of the median time or of the peak heap, in percent, above which a part counts as
+    /// regressed
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// Day of the puzzle (1-25)
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Size in the day's own unit, e.g. digits of the disk map or bits of the adder
+    /// (default: about the size of a real input)
+    #[arg(long)]
+    size: Option<usize>,
+    /// Seed of the random generator; the same seed and size give the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Solve the generated input and compare with the answers its construction guarantees
+    #[arg(long)]
+    check: bool,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// Day of the puzzle (1-25)
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Seconds after which a part is killed
+    #[arg(long, default_value = "60", value_parser = parse_timeout)]
+    timeout: Duration,
+}
+
+/// A `--timeout` in seconds, which has to be a positive, finite number of them.
+fn parse_timeout(text: &str) -> std::result::Result<Duration, String> {
+    let seconds: f64 = text.parse().map_err(|_| "expected a number of seconds".to_string())?;
+    if !seconds.is_finite() || seconds <= 0.0 {
+        return Err("expected a positive, finite number of seconds".to_string());
+    }
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("{} seconds is too long", text))
+}
+
+impl RunArgs {
+    fn input_source(&self, day: u8) -> InputSource {
+        if self.example {
+            InputSource::File(common::day_dir(day).join(EXAMPLE_FILE))
+        } else if self.all {
+            // One AOC_INPUT cannot be the input of every day.
+            InputSource::File(common::input_path(day))
+        } else {
+            common::resolve_input(day, self.input.clone().map(PathBuf::into_os_string))
+        }
+    }
+}
+
+/// Reads an input, reporting a missing file by name rather than as an I/O error.
+fn read_source(source: &InputSource) -> Result<String> {
+    if let InputSource::File(path) = source {
+        if !path.exists() {
+            bail!("Input file {} not found", path.display());
+        }
+    }
+    Ok(source.read()?)
+}
+
+fn solve(day: &days::Day, part: u8, contents: &str) -> Result<(Answer, Duration)> {
+    let Some(solver) = day.solver(part) else {
+        bail!("Day {:02} has no part {}", day.day, part);
+    };
+    let start = Instant::now();
+    let answer = solver(contents)?;
+    Ok((answer, start.elapsed()))
+}
+
+fn solve_file(day: &days::Day, part: u8, path: &Path) -> Result<(Answer, Duration)> {
+    let contents = read_source(&InputSource::File(path.to_path_buf()))?;
+    solve(day, part, &contents)
+}
+
+fn run(args: &RunArgs) -> Result<()> {
+    if args.all {
+        return run_all(args);
+    }
+    let day_number = args.day.expect("clap requires a day without --all");
+    let Some(day) = days::find(day_number) else {
+        bail!("Day {:02} is not solved yet", day_number);
+    };
+    let parts = match args.part {
+        Some(part) => part..=part,
+        None => 1..=day.parts.len() as u8,
+    };
+
+    // Read once, so both parts see the same standard input.
+    let contents = read_source(&args.input_source(day.day)).with_context(|| format!("Day {:02}", day.day))?;
+    let mut failed = 0;
+    for part in parts {
+        let Some(solver) = day.solver(part) else {
+            eprintln!("Day {:02} has no part {}", day.day, part);
+            failed += 1;
+            continue;
+        };
+        match Record::solve(day.day, part, solver, &contents) {
+            Ok(record) if args.format == Format::Json => println!("{}", record.to_json()),
+            Ok(record) => {
+                let elapsed = Duration::from_nanos(record.parse_ns + record.solve_ns);
+                println!("Day {:02} part {}: {} ({:?})", day.day, part, record.answer, elapsed);
+            }
+            Err(err) => {
+                eprintln!("Day {:02} part {}: {}", day.day, part, err);
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        bail!("{} part(s) did not succeed", failed);
+    }
+    Ok(())
+}
+
+/// `run --all`: every part of every day on a pool of child processes.
+fn run_all(args: &RunArgs) -> Result<()> {
+    let known = answers::load_known()?;
+    let tasks: Vec<(u8, u8)> = days::DAYS
+        .iter()
+        .flat_map(|day| (1..=day.parts.len() as u8).map(move |part| (day.day, part)))
+        .collect();
+    let jobs = args.jobs.map_or_else(runner::default_jobs, |jobs| jobs as usize);
+    let timeout = args.timeout;
+
+    let start = Instant::now();
+    let results = runner::parallel_map(&tasks, jobs, |&(day, part)| {
+        let path = match args.input_source(day) {
+            InputSource::File(path) => path,
+            InputSource::Stdin => unreachable!("--all always reads files"),
+        };
+        if !path.exists() {
+            return PartResult::new(day, part, Status::Error(format!("Input file {} not found", path.display())));
+        }
+        let expected = answers::lookup(&known, day, part, &path);
+        let mut command = match std::env::current_exe() {
+            Ok(exe) => std::process::Command::new(exe),
+            Err(err) => return PartResult::new(day, part, Status::Error(err.to_string())),
+        };
+        command.args(["run", &day.to_string(), &part.to_string(), "--format", "json", "--input"]);
+        command.arg(&path);
+        runner::run_part(&mut command, day, part, expected, timeout)
+    });
+    let summary = Summary {
+        results,
+        wall: start.elapsed(),
+        jobs,
+    };
+
+    if args.format == Format::Json {
+        for record in summary.results.iter().filter_map(|result| result.record.as_ref()) {
+            println!("{}", record.to_json());
+        }
+        eprintln!("{}", summary.totals());
+    } else {
+        print!("{}", summary.table());
+    }
+
+    if let Some(path) = &args.report {
+        fs::write(path, summary.render_for(path))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        eprintln!("Wrote {}", path.display());
+    }
+    let failures = summary.failures();
+    if failures > 0 {
+        bail!("{} part(s) did not succeed", failures);
+    }
+    Ok(())
+}
+
+fn verify(args: &VerifyArgs) -> Result<()> {
+    let path = args.answers.clone().unwrap_or_else(answers::default_path);
+    let expected = answers::load(&path)?;
+
+    println!("Day  Part  Input            Status         Time  Answer");
+
+    let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+    for entry in expected.iter().filter(|e| args.day.is_none_or(|day| e.day == day)) {
+        let input_path = entry.input_path();
+        let (status, time, detail) = if !input_path.exists() {
+            skipped += 1;
+            ("SKIP", String::new(), "input not found".to_string())
+        } else {
+            let result = days::find(entry.day)
+                .ok_or_else(|| anyhow::anyhow!("Day {:02} is not solved yet", entry.day))
+                .and_then(|day| solve_file(day, entry.part, &input_path));
+            match result {
+                Ok((answer, elapsed)) if answer == entry.answer => {
+                    passed += 1;
+                    ("PASS", format!("{:?}", elapsed), answer.to_string())
+                }
+                Ok((answer, elapsed)) => {
+                    failed += 1;
+                    let detail = format!("{} (expected {})", answer, entry.answer);
+                    ("FAIL", format!("{:?}", elapsed), detail)
+                }
+                Err(err) => {
+                    failed += 1;
+                    ("FAIL", String::new(), err.to_string())
+                }
+            }
+        };
+        println!(
+            "{:<4} {:<5} {:<16} {:<6} {:>12}  {}",
+            format!("{:02}", entry.day),
+            entry.part,
+            entry.input,
+            status,
+            time,
+            detail
+        );
+    }
+
+    println!("\n{} passed, {} failed, {} skipped", passed, failed, skipped);
+    if failed > 0 {
+        bail!("{} answer(s) did not match", failed);
+    }
+    Ok(())
+}
+
+fn new_day(args: &NewArgs) -> Result<()> {
+    let dir = scaffold::new_day(&common::workspace_dir(), args.day, args.year)?;
+    println!("Created {}", dir.display());
+    Ok(())
+}
+
+fn fetch(args: &FetchArgs) -> Result<()> {
+    let cache_file = common::cached_input_path(args.year, args.day);
+    let session = client::session_token(&common::workspace_dir())?;
+    let throttle_file = common::cache_dir(args.year).join(".last-request");
+    let client = client::Client::new(&args.base_url, session, throttle_file);
+
+    if client::fetch_input(&client, args.year, args.day, &cache_file, args.force)? {
+        println!("Downloaded {}", cache_file.display());
+    } else {
+        println!("{} is already cached", cache_file.display());
+    }
+    Ok(())
+}
+
+fn submit(args: &SubmitArgs) -> Result<()> {
+    let Some(day) = days::find(args.day) else {
+        bail!("Day {:02} is not solved yet", args.day);
+    };
+    let source = common::resolve_input(args.day, args.input.clone().map(PathBuf::into_os_string));
+    let (answer, elapsed) = solve(day, args.part, &read_source(&source)?)?;
+    println!("Day {:02} part {}: {} ({:?})", args.day, args.part, answer, elapsed);
+
+    let year = common::YEAR;
+    let session = client::session_token(&common::workspace_dir())?;
+    let throttle_file = common::cache_dir(year).join(".last-request");
+    let client = client::Client::new(&args.base_url, session, throttle_file);
+    let mut history = submit::History::load(&common::cache_dir(year).join(submit::GUESSES_FILE))?;
+
+    let verdict = submit::submit_answer(&client, &mut history, year, args.day, args.part, &answer)?;
+    if verdict != submit::Verdict::Correct {
+        bail!("{}", verdict);
+    }
+    println!("{}", verdict);
+    Ok(())
+}
+
+fn update_readme(args: &ReadmeArgs) -> Result<()> {
+    let stats_path = args.stats.clone().unwrap_or_else(readme::default_stats_path);
+    let stats: readme::PersonalStats = readme::load_json(&stats_path)?;
+    if stats.year != common::YEAR {
+        bail!("{} holds stats for {}, not {}", stats_path.display(), stats.year, common::YEAR);
+    }
+    let leaderboard: Option<readme::Leaderboard> =
+        args.leaderboard.as_deref().map(readme::load_json).transpose()?;
+
+    let mut runtimes = BTreeMap::new();
+    if !args.no_runtimes {
+        for day in days::DAYS {
+            let path = common::input_path(day.day);
+            if !path.exists() {
+                continue;
+            }
+            for part in 1..=day.parts.len() as u8 {
+                match solve_file(day, part, &path) {
+                    Ok((_, elapsed)) => {
+                        runtimes.insert((day.day, part), elapsed);
+                    }
+                    Err(err) => eprintln!("Day {:02} part {}: {}", day.day, part, err),
+                }
+            }
+        }
+    }
+
+    let section = readme::render(
+        &stats,
+        leaderboard.as_ref().map(|l| (l, args.member.as_deref())),
+        &runtimes,
+    )?;
+    let readme_path = common::workspace_dir().join("README.md");
+    let contents = read_input(&readme_path)?;
+    fs::write(&readme_path, readme::replace_section(&contents, &section)?)?;
+    println!("Updated {} ({} runtimes measured)", readme_path.display(), runtimes.len());
+    Ok(())
+}
+
+fn run_benchmarks(args: &BenchArgs) -> Result<()> {
+    let config = common::bench::BenchConfig {
+        warmup: args.warmup,
+        iterations: args.iterations,
+    };
+    let mut report = bench::Report::default();
+
+    // Allocations are only counted in builds with the count-allocations feature.
+    let memory = common::alloc::is_installed();
+    if memory {
+        println!("Day  Part        Parse        Solve    Solve p95        Total      Allocs   Peak heap  Answer");
+    } else {
+        println!("Day  Part        Parse        Solve    Solve p95        Total  Answer");
+    }
+    for day in days::DAYS.iter().filter(|d| args.day.is_none_or(|day| d.day == day)) {
+        let path = if args.example {
+            common::day_dir(day.day).join(EXAMPLE_FILE)
+        } else {
+            common::input_path(day.day)
+        };
+        if !path.exists() {
+            eprintln!("Day {:02}: {} not found, skipped", day.day, path.display());
+            continue;
+        }
+        let contents = read_input(&path)?;
+
+        for part in 1..=day.parts.len() as u8 {
+            if args.part.is_some_and(|p| p != part) {
+                continue;
+            }
+            let solver = day.solver(part).expect("part is within the day's parts");
+            let measurement = common::bench::measure(day.day, part, solver, &contents, config)?;
+            let allocations = measurement.memory.map_or(String::new(), |m| {
+                format!(
+                    " {:>11} {:>11}",
+                    m.parse.allocations + m.solve.allocations,
+                    report::format_bytes(m.peak_bytes())
+                )
+            });
+            println!(
+                "{:<4} {:<4} {:>12} {:>12} {:>12} {:>12}{}  {}",
+                format!("{:02}", day.day),
+                part,
+                bench::format_ns(measurement.parse.median_ns),
+                bench::format_ns(measurement.solve.median_ns),
+                bench::format_ns(measurement.solve.p95_ns),
+                bench::format_ns(measurement.total.median_ns),
+                allocations,
+                measurement.answer
+            );
+            report.results.push(measurement);
+        }
+    }
+
+    if let Some(path) = &args.json {
+        report.save(path)?;
+        println!("\nWrote {}", path.display());
+    }
+
+    if let Some(path) = &args.baseline {
+        let baseline = bench::Report::load(path)?;
+        let comparisons = bench::compare(&report, &baseline, args.threshold);
+        println!("\nDay  Part     Baseline      Current    Change               Peak heap    Change");
+        for c in &comparisons {
+            let peak = match (c.peak_bytes, c.peak_change) {
+                (Some((before, now)), Some(change)) => format!(
+                    "  {:>22} {:>+8.1}%",
+                    format!("{}->{}", report::format_bytes(before), report::format_bytes(now)),
+                    change
+                ),
+                _ => String::new(),
+            };
+            println!(
+                "{:<4} {:<4} {:>12} {:>12} {:>+8.1}%{}{}",
+                format!("{:02}", c.day),
+                c.part,
+                bench::format_ns(c.baseline_ns),
+                bench::format_ns(c.current_ns),
+                c.change,
+                peak,
+                if c.regressed { "  REGRESSED" } else { "" }
+            );
+        }
+        let regressed = comparisons.iter().filter(|c| c.regressed).count();
+        if regressed > 0 {
+            bail!("{} part(s) regressed by more than {}%", regressed, args.threshold);
+        }
+    }
+    Ok(())
+}
+
+fn generate(args: &GenArgs) -> Result<()> {
+    let Some(day) = days::find(args.day) else {
+        bail!("Day {:02} is not solved yet", args.day);
+    };
+    let Some(generate) = day.generate else {
+        bail!("Day {:02} has no input generator", args.day);
+    };
+    let options = GenOptions {
+        size: args.size,
+        seed: args.seed,
+    };
+    let generated = generate(&options);
+    if !args.check {
+        print!("{}", generated.input);
+        return Ok(());
+    }
+
+    let checks = common::generator::check(day.parts, &generated);
+    for check in &checks {
+        println!("Day {:02} part {}: {} ({:?})", day.day, check.part, check.outcome, check.time);
+    }
+    let failed = checks.iter().filter(|check| !check.outcome.is_ok()).count();
+    if failed > 0 {
+        bail!("{} part(s) failed on the input generated with seed {}", failed, args.seed);
+    }
+    Ok(())
+}
+
+/// Runs the subcommand; every handler reports failure as an error, printed here.
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = cli.trace.init().and_then(|_guard| match &cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::New(args) => new_day(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::Readme(args) => update_readme(args),
+        Command::Bench(args) => run_benchmarks(args),
+        Command::Gen(args) => generate(args),
+        Command::Watch(args) => watch::watch(args.day, args.timeout),
+    });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{:#}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
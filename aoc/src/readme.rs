@@ -0,0 +1,261 @@
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/*
+`aoc readme`: regenerates the overview and leaderboard table of README.md.
+
+Everything between the `START_MARKER` and `END_MARKER` comments is replaced. The data comes from
+`stats/personal.json`, transcribed from the personal stats page:
+
+    {"year": 2024, "days": [
+        {"day": 1, "part1": {"time": "00:16:50", "rank": 6017, "score": 0}, "part2": {...}}
+    ]}
+
+Optionally it also reads a private leaderboard as served by the puzzle server's `.json` API. The
+runtime columns hold measurements taken by the runner.
+*/
+
+pub const START_MARKER: &str = "<!-- leaderboard:start -->";
+pub const END_MARKER: &str = "<!-- leaderboard:end -->";
+pub const STATS_FILE: &str = "stats/personal.json";
+
+#[derive(Debug, Deserialize)]
+pub struct PersonalStats {
+    pub year: u16,
+    pub days: Vec<DayStats>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DayStats {
+    pub day: u8,
+    pub part1: Option<PartStats>,
+    pub part2: Option<PartStats>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PartStats {
+    /// Time from unlock to the star, as shown on the stats page.
+    pub time: String,
+    pub rank: u32,
+    /// Global leaderboard points.
+    pub score: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub owner_id: u64,
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+}
+
+impl Leaderboard {
+    /// Position (1-based) and entry of the member named or numbered `who`, or of the owner.
+    fn standing(&self, who: Option<&str>) -> Result<(usize, &Member)> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by(|a, b| b.local_score.cmp(&a.local_score).then(a.id.cmp(&b.id)));
+        members
+            .iter()
+            .position(|m| match who {
+                Some(who) => m.name.as_deref() == Some(who) || m.id.to_string() == who,
+                None => m.id == self.owner_id,
+            })
+            .map(|i| (i + 1, members[i]))
+            .with_context(|| format!("{} is not on the leaderboard", who.unwrap_or("The owner")))
+    }
+}
+
+pub fn default_stats_path() -> PathBuf {
+    common::workspace_dir().join(STATS_FILE)
+}
+
+pub fn load_json<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<T> {
+    let contents = fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&contents).with_context(|| format!("Invalid JSON in {}", path.display()))
+}
+
+/// "12.3 ms", "456 µs" or "1.20 s".
+pub fn format_runtime(runtime: Duration) -> String {
+    let micros = runtime.as_secs_f64() * 1e6;
+    if micros < 1000.0 {
+        format!("{:.0} µs", micros)
+    } else if micros < 1e6 {
+        format!("{:.1} ms", micros / 1e3)
+    } else {
+        format!("{:.2} s", micros / 1e6)
+    }
+}
+
+/// The generated section, markers included.
+pub fn render(
+    stats: &PersonalStats,
+    leaderboard: Option<(&Leaderboard, Option<&str>)>,
+    runtimes: &BTreeMap<(u8, u8), Duration>,
+) -> Result<String> {
+    let stars = stats
+        .days
+        .iter()
+        .map(|d| d.part1.is_some() as u32 + d.part2.is_some() as u32)
+        .sum::<u32>();
+    let points: u32 = stats
+        .days
+        .iter()
+        .flat_map(|d| [&d.part1, &d.part2])
+        .flatten()
+        .map(|p| p.score)
+        .sum();
+
+    let mut out = String::new();
+    writeln!(out, "{}", START_MARKER)?;
+    writeln!(out, "## Overview")?;
+    writeln!(out, "**Stars Earned:** {}/50  ", stars)?;
+    writeln!(out, "**Total Points:** {}  ", points)?;
+    if let Some((leaderboard, who)) = leaderboard {
+        let (position, member) = leaderboard.standing(who)?;
+        writeln!(
+            out,
+            "**Private Leaderboard:** #{} of {} ({} points, {} stars)  ",
+            position,
+            leaderboard.members.len(),
+            member.local_score,
+            member.stars
+        )?;
+    }
+    writeln!(out)?;
+    writeln!(out, "## Leaderboard Stats")?;
+    writeln!(out)?;
+    let header = [
+        "Day",
+        "Part 1 Time",
+        "Rank",
+        "Part 2 Time",
+        "Rank",
+        "Points",
+        "Part 1 Runtime",
+        "Part 2 Runtime",
+    ];
+    let mut rows = Vec::new();
+    let mut days: Vec<&DayStats> = stats.days.iter().collect();
+    days.sort_by_key(|d| std::cmp::Reverse(d.day));
+    for day in days {
+        let part = |part: &Option<PartStats>| match part {
+            Some(p) => (p.time.clone(), p.rank.to_string(), p.score),
+            None => ("-".to_string(), "-".to_string(), 0),
+        };
+        let (time1, rank1, score1) = part(&day.part1);
+        let (time2, rank2, score2) = part(&day.part2);
+        let runtime = |part: u8| {
+            runtimes
+                .get(&(day.day, part))
+                .map_or("-".to_string(), |r| format_runtime(*r))
+        };
+        rows.push([
+            day.day.to_string(),
+            time1,
+            rank1,
+            time2,
+            rank2,
+            (score1 + score2).to_string(),
+            runtime(1),
+            runtime(2),
+        ]);
+    }
+
+    // Every column as wide as its widest cell, so the table lines up in the source too.
+    let mut widths = header.map(|title| title.len());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let line = |cells: [&str; 8]| {
+        let cells = cells.iter().zip(widths).map(|(cell, width)| format!(" {:<width$} ", cell));
+        format!("|{}|", cells.collect::<Vec<_>>().join("|"))
+    };
+    writeln!(out, "{}", line(header))?;
+    let rule: Vec<String> = widths.iter().map(|width| "-".repeat(width + 2)).collect();
+    writeln!(out, "|{}|", rule.join("|"))?;
+    for row in &rows {
+        writeln!(out, "{}", line(row.each_ref().map(String::as_str)))?;
+    }
+    write!(out, "{}", END_MARKER)?;
+    Ok(out)
+}
+
+/// Replaces the marked section of `readme` with `section`.
+pub fn replace_section(readme: &str, section: &str) -> Result<String> {
+    let (Some(start), Some(end)) = (readme.find(START_MARKER), readme.find(END_MARKER)) else {
+        bail!("README.md has no {} ... {} section", START_MARKER, END_MARKER);
+    };
+    if end < start {
+        bail!("{} comes before {} in README.md", END_MARKER, START_MARKER);
+    }
+    Ok(format!("{}{}{}", &readme[..start], section, &readme[end + END_MARKER.len()..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATS: &str = r#"{"year": 2024, "days": [
+        {"day": 1, "part1": {"time": "00:16:50", "rank": 6017, "score": 0}, "part2": {"time": "00:21:55", "rank": 5526, "score": 0}},
+        {"day": 5, "part1": {"time": "00:55:20", "rank": 10397, "score": 0}, "part2": {"time": "00:57:20", "rank": 6650, "score": 0}},
+        {"day": 21, "part1": {"time": "00:53:42", "rank": 285, "score": 0}, "part2": {"time": "00:58:06", "rank": 79, "score": 22}},
+        {"day": 22, "part1": {"time": "00:06:46", "rank": 692, "score": 0}, "part2": null}
+    ]}"#;
+
+    const LEADERBOARD: &str = r#"{"event": "2024", "owner_id": 7, "members": {
+        "7": {"id": 7, "name": "mario", "stars": 50, "local_score": 900, "global_score": 22},
+        "9": {"id": 9, "name": null, "stars": 48, "local_score": 950, "global_score": 0}
+    }}"#;
+
+    #[test]
+    fn test_render() {
+        let stats: PersonalStats = serde_json::from_str(STATS).unwrap();
+        let leaderboard: Leaderboard = serde_json::from_str(LEADERBOARD).unwrap();
+        let runtimes = BTreeMap::from([((1, 1), Duration::from_micros(250)), ((21, 2), Duration::from_millis(12))]);
+        let section = render(&stats, Some((&leaderboard, None)), &runtimes).unwrap();
+
+        assert!(section.contains("**Stars Earned:** 7/50  \n**Total Points:** 22  \n"));
+        assert!(section.contains("**Private Leaderboard:** #2 of 2 (900 points, 50 stars)"));
+        let rows: Vec<&str> = section.lines().filter(|l| l.starts_with("| ") && !l.starts_with("| Day")).collect();
+        assert_eq!(
+            rows,
+            vec![
+                "| 22  | 00:06:46    | 692   | -           | -    | 0      | -              | -              |",
+                "| 21  | 00:53:42    | 285   | 00:58:06    | 79   | 22     | -              | 12.0 ms        |",
+                "| 5   | 00:55:20    | 10397 | 00:57:20    | 6650 | 0      | -              | -              |",
+                "| 1   | 00:16:50    | 6017  | 00:21:55    | 5526 | 0      | 250 µs         | -              |",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = format!("# Title\n\n{}\nold\n{}\n\n## Running\n", START_MARKER, END_MARKER);
+        let section = format!("{}\nnew\n{}", START_MARKER, END_MARKER);
+        assert_eq!(
+            replace_section(&readme, &section).unwrap(),
+            format!("# Title\n\n{}\nnew\n{}\n\n## Running\n", START_MARKER, END_MARKER)
+        );
+        assert!(replace_section("# Title\n", &section).is_err());
+    }
+
+    #[test]
+    fn test_unknown_member() {
+        let leaderboard: Leaderboard = serde_json::from_str(LEADERBOARD).unwrap();
+        assert_eq!(leaderboard.standing(Some("9")).unwrap().0, 1);
+        assert!(leaderboard.standing(Some("luigi")).is_err());
+    }
+}
@@ -0,0 +1,262 @@
+use crate::readme::format_runtime;
+use common::{Answer, Record};
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
+
+/*
+Summary of `aoc run --all`: a table for the terminal, and the same table as a Markdown or HTML
+report for `--report`.
+*/
+
+/// How one part fared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// Matches the answer recorded in answers.toml.
+    Pass,
+    /// Differs from the recorded answer, which is kept here.
+    Fail(Answer),
+    /// Solved, but there is no recorded answer to compare with.
+    Solved,
+    /// The solution returned an error, or the part could not be run.
+    Error(String),
+    /// The process died, e.g. from a panic.
+    Crash(String),
+    TimedOut(Duration),
+}
+
+impl Status {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "PASS",
+            Status::Fail(_) => "FAIL",
+            Status::Solved => "SOLVED",
+            Status::Error(_) => "ERROR",
+            Status::Crash(_) => "CRASH",
+            Status::TimedOut(_) => "TIMEOUT",
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Status::Pass | Status::Solved)
+    }
+}
+
+/// One row of the summary.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    /// What the part printed, if it got as far as an answer.
+    pub record: Option<Record>,
+    pub peak_bytes: Option<u64>,
+}
+
+impl PartResult {
+    pub fn new(day: u8, part: u8, status: Status) -> Self {
+        Self {
+            day,
+            part,
+            status,
+            record: None,
+            peak_bytes: None,
+        }
+    }
+
+    /// Parse and solve time as measured by the part itself.
+    pub fn time(&self) -> Option<Duration> {
+        self.record.as_ref().map(|r| Duration::from_nanos(r.parse_ns + r.solve_ns))
+    }
+
+    /// The answer, or what went wrong instead.
+    pub fn detail(&self) -> String {
+        match (&self.status, self.record.as_ref().map(|r| &r.answer)) {
+            (Status::Fail(expected), Some(answer)) => format!("{} (expected {})", answer, expected),
+            (Status::Error(message) | Status::Crash(message), _) => message.clone(),
+            (Status::TimedOut(timeout), _) => format!("killed after {}", format_runtime(*timeout)),
+            (_, Some(answer)) => answer.to_string(),
+            (_, None) => String::new(),
+        }
+    }
+}
+
+/// Results of a whole run.
+#[derive(Debug, Clone)]
+pub struct Summary {
+    pub results: Vec<PartResult>,
+    pub wall: Duration,
+    pub jobs: usize,
+}
+
+impl Summary {
+    pub fn failures(&self) -> usize {
+        self.results.iter().filter(|r| r.status.is_failure()).count()
+    }
+
+    /// One line of counts, e.g. `47 passed, 1 failed, 1 unchecked in 2.31 s on 8 threads`.
+    pub fn totals(&self) -> String {
+        let count = |f: fn(&Status) -> bool| self.results.iter().filter(|r| f(&r.status)).count();
+        format!(
+            "{} passed, {} failed, {} unchecked in {} on {} thread{}",
+            count(|s| *s == Status::Pass),
+            count(Status::is_failure),
+            count(|s| *s == Status::Solved),
+            format_runtime(self.wall),
+            self.jobs,
+            if self.jobs == 1 { "" } else { "s" }
+        )
+    }
+
+    fn rows(&self) -> impl Iterator<Item = [String; 6]> + '_ {
+        self.results.iter().map(|r| {
+            [
+                format!("{:02}", r.day),
+                r.part.to_string(),
+                r.status.label().to_string(),
+                r.time().map(format_runtime).unwrap_or_default(),
+                r.peak_bytes.map(format_bytes).unwrap_or_default(),
+                r.detail(),
+            ]
+        })
+    }
+
+    /// The table printed to the terminal.
+    pub fn table(&self) -> String {
+        let mut out = "Day  Part  Status          Time      Memory  Answer\n".to_string();
+        for [day, part, status, time, memory, detail] in self.rows() {
+            writeln!(out, "{:<4} {:<5} {:<7} {:>12} {:>11}  {}", day, part, status, time, memory, detail).unwrap();
+        }
+        writeln!(out, "\n{}", self.totals()).unwrap();
+        out
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = "# Advent of Code 2024\n\n".to_string();
+        out += "| Day | Part | Status | Time | Memory | Answer |\n";
+        out += "| ---: | ---: | :--- | ---: | ---: | :--- |\n";
+        for row in self.rows() {
+            let cells: Vec<String> = row.iter().map(|cell| cell.replace('|', "\\|")).collect();
+            writeln!(out, "| {} |", cells.join(" | ")).unwrap();
+        }
+        writeln!(out, "\n{}", self.totals()).unwrap();
+        out
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut out = String::from(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Advent of Code 2024</title>\n\
+             <style>\n\
+             body { font-family: sans-serif; }\n\
+             td, th { padding: 0.2em 0.8em; text-align: right; }\n\
+             td:last-child { text-align: left; font-family: monospace; }\n\
+             .PASS { color: green; } .FAIL, .ERROR, .CRASH, .TIMEOUT { color: red; }\n\
+             </style>\n</head>\n<body>\n<h1>Advent of Code 2024</h1>\n<table>\n\
+             <tr><th>Day</th><th>Part</th><th>Status</th><th>Time</th><th>Memory</th><th>Answer</th></tr>\n",
+        );
+        for [day, part, status, time, memory, detail] in self.rows() {
+            writeln!(
+                out,
+                "<tr><td>{}</td><td>{}</td><td class=\"{}\">{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                day,
+                part,
+                status,
+                status,
+                time,
+                memory,
+                escape_html(&detail)
+            )
+            .unwrap();
+        }
+        writeln!(out, "</table>\n<p>{}</p>\n</body>\n</html>", self.totals()).unwrap();
+        out
+    }
+
+    /// The report for `path`: HTML for `.html` and `.htm` files, Markdown otherwise.
+    pub fn render_for(&self, path: &Path) -> String {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("html" | "htm") => self.to_html(),
+            _ => self.to_markdown(),
+        }
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    let kib = bytes as f64 / 1024.0;
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if kib < 1024.0 {
+        format!("{:.0} KiB", kib)
+    } else if kib < 1024.0 * 1024.0 {
+        format!("{:.1} MiB", kib / 1024.0)
+    } else {
+        format!("{:.2} GiB", kib / (1024.0 * 1024.0))
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary() -> Summary {
+        let result = |part, status, answer: Option<u64>| PartResult {
+            day: 7,
+            part,
+            status,
+            record: answer.map(|answer| Record {
+                day: 7,
+                part,
+                answer: Answer::Number(answer),
+                parse_ns: 500_000,
+                solve_ns: 1_000_000,
+            }),
+            peak_bytes: Some(3 << 20),
+        };
+        Summary {
+            results: vec![
+                result(1, Status::Pass, Some(3749)),
+                result(2, Status::Fail(Answer::Number(11387)), Some(1)),
+                PartResult {
+                    peak_bytes: None,
+                    ..result(2, Status::Crash("a|b <panicked>".to_string()), None)
+                },
+            ],
+            wall: Duration::from_millis(2310),
+            jobs: 8,
+        }
+    }
+
+    #[test]
+    fn test_table() {
+        let table = summary().table();
+        assert!(table.contains("07   1     PASS          1.5 ms     3.0 MiB  3749\n"), "{}", table);
+        assert!(table.contains("FAIL          1.5 ms     3.0 MiB  1 (expected 11387)\n"));
+        assert!(table.ends_with("\n1 passed, 2 failed, 0 unchecked in 2.31 s on 8 threads\n"));
+        let single = Summary { jobs: 1, ..summary() };
+        assert!(single.totals().ends_with(" on 1 thread"), "{}", single.totals());
+    }
+
+    #[test]
+    fn test_reports() {
+        let summary = summary();
+        let markdown = summary.render_for(Path::new("report.md"));
+        assert!(markdown.contains("| 07 | 1 | PASS | 1.5 ms | 3.0 MiB | 3749 |\n"), "{}", markdown);
+        assert!(markdown.contains("| 07 | 2 | CRASH |  |  | a\\|b <panicked> |\n"));
+
+        let html = summary.render_for(Path::new("report.html"));
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<td class=\"CRASH\">CRASH</td><td></td><td></td><td>a|b &lt;panicked&gt;</td>"));
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(96), "96 B");
+        assert_eq!(format_bytes(512 * 1024), "512 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+        assert_eq!(format_bytes(5 << 30), "5.00 GiB");
+    }
+}
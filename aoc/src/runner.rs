@@ -0,0 +1,262 @@
+use crate::report::{PartResult, Status};
+use anyhow::{Context, Result};
+use common::{Answer, Record};
+use std::io::Read;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/*
+Machinery of `aoc run --all`: a pool of threads, each running one part at a time in a child process.
+
+The child is the runner itself, solving a single part with `--format json`. A process per part keeps
+a panic, which aborts the whole process in release builds, from taking the other parts down, lets a
+part that runs past its timeout be killed, and gives every part its own peak memory.
+*/
+
+/// How often a waiting thread checks whether its child has exited.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// How a child process ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Exit {
+    Code(i32),
+    /// Killed by a signal, e.g. the abort of a panic.
+    Signal(i32),
+    /// Killed by us for running past its timeout.
+    TimedOut,
+}
+
+/// Everything a child process left behind.
+#[derive(Debug)]
+pub struct ChildRun {
+    pub exit: Exit,
+    pub stdout: String,
+    pub stderr: String,
+    /// Peak resident set size in bytes, where the platform reports it. Linux counts the runner's
+    /// own footprint at the time of the spawn into it, which puts a floor of a few MiB under it.
+    pub peak_bytes: Option<u64>,
+}
+
+/// Runs `command` to completion, killing it once it has run for `timeout`.
+pub fn run_isolated(command: &mut Command, timeout: Duration) -> Result<ChildRun> {
+    let deadline = Instant::now() + timeout;
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to start {:?}", command.get_program()))?;
+
+    // Drain both pipes while waiting, so a chatty child never blocks on a full pipe.
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+    let (exit, peak_bytes) = wait(&mut child, deadline)?;
+
+    Ok(ChildRun {
+        exit,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+        peak_bytes,
+    })
+}
+
+fn drain(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+/// Reaps `child` with `wait4`, which also reports the peak memory of the child alone.
+#[cfg(unix)]
+fn wait(child: &mut Child, deadline: Instant) -> Result<(Exit, Option<u64>)> {
+    let pid = child.id() as libc::pid_t;
+    let mut timed_out = false;
+    loop {
+        let mut status = 0;
+        // SAFETY: rusage is plain data, for which all zeroes is a valid value.
+        let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+        let flags = if timed_out { 0 } else { libc::WNOHANG };
+        // SAFETY: `pid` is our own child, not reaped yet, and both pointers are valid for writes.
+        let reaped = unsafe { libc::wait4(pid, &mut status, flags, &mut usage) };
+        if reaped < 0 {
+            return Err(std::io::Error::last_os_error()).context("Failed to wait for a child process");
+        }
+        if reaped == pid {
+            // Linux reports kilobytes, macOS bytes.
+            let unit = if cfg!(target_os = "macos") { 1 } else { 1024 };
+            let peak_bytes = Some(usage.ru_maxrss as u64 * unit);
+            let exit = if timed_out {
+                Exit::TimedOut
+            } else if libc::WIFSIGNALED(status) {
+                Exit::Signal(libc::WTERMSIG(status))
+            } else {
+                Exit::Code(libc::WEXITSTATUS(status))
+            };
+            return Ok((exit, peak_bytes));
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            timed_out = true;
+        } else {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn wait(child: &mut Child, deadline: Instant) -> Result<(Exit, Option<u64>)> {
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok((Exit::Code(status.code().unwrap_or(-1)), None));
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Ok((Exit::TimedOut, None));
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Runs `command`, which solves `part` of `day` and prints its record as JSON, and judges its
+/// answer against `expected`.
+pub fn run_part(command: &mut Command, day: u8, part: u8, expected: Option<&Answer>, timeout: Duration) -> PartResult {
+    let mut result = PartResult::new(day, part, Status::Solved);
+    let child = match run_isolated(command, timeout) {
+        Ok(child) => child,
+        Err(err) => {
+            result.status = Status::Error(format!("{:#}", err));
+            return result;
+        }
+    };
+    result.peak_bytes = child.peak_bytes;
+
+    // The last line of the child's errors, minus what the table already says.
+    let prefix = format!("Day {:02} part {}: ", day, part);
+    let message = child
+        .stderr
+        .lines()
+        .rfind(|line| !line.is_empty() && !line.starts_with("note: "))
+        .map(|line| line.strip_prefix(&prefix).or(line.strip_prefix("Error: ")).unwrap_or(line))
+        .unwrap_or_default()
+        .to_string();
+    result.status = match child.exit {
+        Exit::TimedOut => Status::TimedOut(timeout),
+        Exit::Signal(signal) => Status::Crash(format!("killed by signal {}: {}", signal, message)),
+        Exit::Code(0) => match serde_json::from_str::<Record>(child.stdout.trim()) {
+            Ok(record) => {
+                let status = match expected {
+                    Some(expected) if *expected == record.answer => Status::Pass,
+                    Some(expected) => Status::Fail(expected.clone()),
+                    None => Status::Solved,
+                };
+                result.record = Some(record);
+                status
+            }
+            Err(err) => Status::Error(format!("Unreadable output of the part: {}", err)),
+        },
+        Exit::Code(code) if message.is_empty() => Status::Error(format!("exited with code {}", code)),
+        Exit::Code(_) => Status::Error(message),
+    };
+    result
+}
+
+/// Runs `task` on every item on `jobs` threads and returns the results in the order of the items.
+pub fn parallel_map<T: Sync, R: Send>(items: &[T], jobs: usize, task: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(items.iter().map(|_| None).collect());
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                let result = task(item);
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every item was run"))
+        .collect()
+}
+
+/// Threads to use when the user does not say.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn sh(script: &str) -> Command {
+        let mut command = Command::new("sh");
+        command.args(["-c", script]);
+        command
+    }
+
+    #[test]
+    fn test_run_isolated_captures_output() {
+        let run = run_isolated(&mut sh("echo out; echo err >&2; exit 3"), Duration::from_secs(10)).unwrap();
+        assert_eq!(run.exit, Exit::Code(3));
+        assert_eq!(run.stdout, "out\n");
+        assert_eq!(run.stderr, "err\n");
+        assert!(run.peak_bytes.is_some_and(|bytes| bytes > 0));
+    }
+
+    #[test]
+    fn test_run_isolated_reports_signal() {
+        let run = run_isolated(&mut sh("kill -ABRT $$"), Duration::from_secs(10)).unwrap();
+        assert_eq!(run.exit, Exit::Signal(libc::SIGABRT));
+    }
+
+    #[test]
+    fn test_run_isolated_kills_on_timeout() {
+        let start = Instant::now();
+        let run = run_isolated(&mut sh("exec sleep 10"), Duration::from_millis(100)).unwrap();
+        assert_eq!(run.exit, Exit::TimedOut);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_run_part_judges_answer() {
+        let record = r#"{"day":1,"part":2,"answer":31,"parse_ns":5,"solve_ns":7}"#;
+        let timeout = Duration::from_secs(10);
+        let expected = Answer::Number(31);
+        let result = run_part(&mut sh(&format!("echo '{}'", record)), 1, 2, Some(&expected), timeout);
+        assert_eq!(result.status, Status::Pass);
+        assert_eq!(result.time(), Some(Duration::from_nanos(12)));
+
+        let wrong = Answer::Number(11);
+        let result = run_part(&mut sh(&format!("echo '{}'", record)), 1, 2, Some(&wrong), timeout);
+        assert_eq!(result.status, Status::Fail(wrong));
+
+        let result = run_part(&mut sh("echo 'Error: no input' >&2; exit 1"), 1, 2, None, timeout);
+        assert_eq!(result.status, Status::Error("no input".to_string()));
+        let result = run_part(&mut sh("echo 'Day 01 part 2: boom' >&2; kill -ABRT $$"), 1, 2, None, timeout);
+        assert_eq!(result.status, Status::Crash(format!("killed by signal {}: boom", libc::SIGABRT)));
+    }
+
+    #[test]
+    fn test_parallel_map_keeps_order() {
+        let items: Vec<u64> = (0..50).collect();
+        let squares = parallel_map(&items, 4, |&n| {
+            thread::sleep(Duration::from_millis(50 - n));
+            n * n
+        });
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+        assert!(parallel_map(&[] as &[u64], 4, |&n| n).is_empty());
+    }
+}
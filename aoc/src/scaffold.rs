@@ -0,0 +1,348 @@
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/*
+`aoc new`: generates a day crate and wires it into the workspace, the runner and the fuzz targets.
+
+The templates below use `DAY_NAME` (day07), `DAY_NUMBER` (7) and `YEAR_NUMBER` (2024) as
+placeholders.
+*/
+
+const CARGO_TEMPLATE: &str = r#"[package]
+name = "DAY_NAME"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+anyhow = "1.0.93"
+common = { path = "../common" }
+tracing = "0.1.41"
+
+[[bin]]
+name = "DAY_NAME-part1"
+path = "src/bin/part1.rs"
+
+[[bin]]
+name = "DAY_NAME-part2"
+path = "src/bin/part2.rs"
+"#;
+
+const LIB_TEMPLATE: &str = r#"use anyhow::{bail, Result};
+use common::{timed_parse, Answer, Input, ParseResult};
+
+pub mod generator;
+
+/*
+https://adventofcode.com/YEAR_NUMBER/day/DAY_NUMBER
+*/
+
+fn parse_input(contents: &str) -> ParseResult<Vec<&str>> {
+    let input = Input::new(DAY_NUMBER, contents);
+    let lines: Vec<&str> = contents.lines().filter(|line| !line.is_empty()).collect();
+    if lines.is_empty() {
+        return Err(input.eof_error("expected puzzle input, found nothing"));
+    }
+    Ok(lines)
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_input(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let lines = timed_parse(|| parse_input(contents))?;
+    bail!("Part 1 is not solved yet ({} lines of input)", lines.len())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let lines = timed_parse(|| parse_input(contents))?;
+    bail!("Part 2 is not solved yet ({} lines of input)", lines.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    #[ignore = "paste the puzzle example into example.txt and fill in its answer"]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(0));
+    }
+
+    #[test]
+    #[ignore = "paste the puzzle example into example.txt and fill in its answer"]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(0));
+    }
+
+    #[test]
+    #[ignore = "solve both parts first"]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 20);
+    }
+
+    common::robustness_test!(#[ignore = "solve both parts first"] parse, [part1, part2], EXAMPLE);
+}
+"#;
+
+const GENERATOR_TEMPLATE: &str = r#"use common::generator::{GenOptions, Generated};
+
+/*
+`size` lines (100 by default) of random numbers, until the generator draws inputs shaped like the
+puzzle's and plants the answers their construction guarantees.
+*/
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let input: String = (0..options.size_or(100)).map(|_| format!("{}\n", rng.u32(..1000))).collect();
+    Generated::unknown(input)
+}
+"#;
+
+const BIN_TEMPLATE: &str = r#"use common::RunOptions;
+
+fn main() -> anyhow::Result<()> {
+    RunOptions::from_args().run(DAY_NUMBER, PART_NUMBER, DAY_NAME::partPART_NUMBER, "Part PART_NUMBER")
+}
+"#;
+
+const FUZZ_TEMPLATE: &str = r#"#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|contents: &str| {
+    let _ = DAY_NAME::parse(contents);
+});
+"#;
+
+const FUZZ_SOLVE_TEMPLATE: &str = r#"#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|contents: &str| {
+    common::fuzz::assert_solves(DAY_NAME::parse, &[DAY_NAME::part1, DAY_NAME::part2], contents);
+});
+"#;
+
+const FUZZ_BIN_TEMPLATE: &str = r#"[[bin]]
+name = "TARGET"
+path = "fuzz_targets/TARGET.rs"
+test = false
+doc = false
+bench = false
+"#;
+
+fn render(template: &str, day: u8, year: u16) -> String {
+    template
+        .replace("DAY_NAME", &format!("day{:02}", day))
+        .replace("DAY_NUMBER", &day.to_string())
+        .replace("YEAR_NUMBER", &year.to_string())
+}
+
+/// Creates `dayNN` under the workspace `root` and registers it with the workspace manifest, the
+/// runner's dependencies and its day registry, and gives it fuzz targets. Returns the new crate's
+/// directory.
+pub fn new_day(root: &Path, day: u8, year: u16) -> Result<PathBuf> {
+    let name = format!("day{:02}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        bail!("{} already exists, refusing to overwrite it", dir.display());
+    }
+
+    // Edit the registrations in memory first so a failure leaves the workspace untouched.
+    let manifest_path = root.join("Cargo.toml");
+    let runner_manifest_path = root.join("aoc/Cargo.toml");
+    let registry_path = root.join("aoc/src/days.rs");
+    let fuzz_manifest_path = root.join("fuzz/Cargo.toml");
+    let manifest = add_workspace_member(&read(&manifest_path)?, &name)?;
+    let runner_manifest = add_day_dependency(&read(&runner_manifest_path)?, &name)
+        .context("aoc/Cargo.toml has no day dependencies")?;
+    let registry = add_registry_entry(&read(&registry_path)?, day)?;
+    let fuzz_manifest = add_fuzz_targets(&read(&fuzz_manifest_path)?, &name)?;
+
+    let fuzz_targets = root.join("fuzz/fuzz_targets");
+    let files = [
+        (dir.join("Cargo.toml"), render(CARGO_TEMPLATE, day, year)),
+        (dir.join("src/lib.rs"), render(LIB_TEMPLATE, day, year)),
+        (dir.join("src/generator.rs"), render(GENERATOR_TEMPLATE, day, year)),
+        (dir.join("src/bin/part1.rs"), render(&BIN_TEMPLATE.replace("PART_NUMBER", "1"), day, year)),
+        (dir.join("src/bin/part2.rs"), render(&BIN_TEMPLATE.replace("PART_NUMBER", "2"), day, year)),
+        (dir.join("example.txt"), String::new()),
+        (fuzz_targets.join(format!("{}.rs", name)), render(FUZZ_TEMPLATE, day, year)),
+        (fuzz_targets.join(format!("{}_solve.rs", name)), render(FUZZ_SOLVE_TEMPLATE, day, year)),
+    ];
+    for (path, contents) in &files {
+        fs::create_dir_all(path.parent().expect("template files live inside a directory"))?;
+        fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+
+    fs::write(&manifest_path, manifest)?;
+    fs::write(&runner_manifest_path, runner_manifest)?;
+    fs::write(&registry_path, registry)?;
+    fs::write(&fuzz_manifest_path, fuzz_manifest)?;
+    Ok(dir)
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+/// Inserts `line` among the lines accepted by `is_entry`, keeping them sorted.
+fn insert_sorted(text: &str, is_entry: impl Fn(&str) -> bool, line: &str) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let last = lines.iter().rposition(|l| is_entry(l))?;
+    let at = lines
+        .iter()
+        .position(|l| is_entry(l) && *l > line)
+        .unwrap_or(last + 1);
+
+    let mut out: Vec<&str> = lines[..at].to_vec();
+    out.push(line);
+    out.extend_from_slice(&lines[at..]);
+    Some(out.join("\n") + "\n")
+}
+
+fn add_workspace_member(manifest: &str, name: &str) -> Result<String> {
+    let member = format!("    \"{}\",", name);
+    if manifest.lines().any(|line| line == member) {
+        bail!("{} is already a workspace member", name);
+    }
+    let is_member = |line: &str| line.starts_with("    \"") && line.ends_with("\",");
+    insert_sorted(manifest, is_member, &member)
+        .context("Cargo.toml has no one-per-line `members` list")
+}
+
+/// Adds the day `name` to the path dependencies on the other days, in the runner or the fuzz crate.
+fn add_day_dependency(manifest: &str, name: &str) -> Option<String> {
+    let dependency = format!("{} = {{ path = \"../{}\" }}", name, name);
+    insert_sorted(manifest, |line| line.starts_with("day"), &dependency)
+}
+
+/// Adds the dependency and the `[[bin]]`s of the day's two fuzz targets, `dayNN` after the other
+/// days' parser targets and `dayNN_solve` after their solver targets.
+fn add_fuzz_targets(manifest: &str, name: &str) -> Result<String> {
+    let mut manifest = add_day_dependency(manifest, name).context("fuzz/Cargo.toml has no day dependencies")?;
+    let order = |target: &str| (target.ends_with("_solve"), target.to_string());
+    for target in [name.to_string(), format!("{}_solve", name)] {
+        let prefix = "[[bin]]\nname = \"";
+        let later = manifest.match_indices(prefix).map(|(at, _)| at).find(|&at| {
+            let other = &manifest[at + prefix.len()..];
+            order(&other[..other.find('"').unwrap_or(0)]) > order(&target)
+        });
+        let bin = FUZZ_BIN_TEMPLATE.replace("TARGET", &target);
+        manifest = match later {
+            Some(at) => format!("{}{}\n{}", &manifest[..at], bin, &manifest[at..]),
+            None => format!("{}\n{}", manifest, bin),
+        };
+    }
+    Ok(manifest)
+}
+
+fn add_registry_entry(registry: &str, day: u8) -> Result<String> {
+    let entry = format!(
+        "    Day {{\n        day: {day},\n        parts: &[day{day:02}::part1, day{day:02}::part2],\n        generate: Some(day{day:02}::generator::generate),\n    }},\n"
+    );
+    let Some(start) = registry.find("pub const DAYS: &[Day] = &[\n") else {
+        bail!("aoc/src/days.rs has no DAYS registry");
+    };
+    let end = start + registry[start..].find("\n];").context("DAYS registry is not closed")? + 1;
+
+    // Entries are sorted by day; insert before the first later day.
+    let mut at = end;
+    let mut search = start;
+    while let Some(offset) = registry[search..end].find("    Day {\n        day: ") {
+        let entry_start = search + offset;
+        let number = &registry[entry_start + "    Day {\n        day: ".len()..];
+        let number: u8 = number[..number.find(',').unwrap_or(0)].parse()?;
+        if number == day {
+            bail!("Day {} is already registered in aoc/src/days.rs", day);
+        }
+        if number > day {
+            at = entry_start;
+            break;
+        }
+        search = entry_start + 1;
+    }
+
+    Ok(format!("{}{}{}", &registry[..at], entry, &registry[at..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day03\",\n]\n";
+    const RUNNER_MANIFEST: &str = "[dependencies]\ncommon = { path = \"../common\" }\nday01 = { path = \"../day01\" }\nday03 = { path = \"../day03\" }\n";
+    const FUZZ_MANIFEST: &str = "[dependencies]\ncommon = { path = \"../common\" }\nday01 = { path = \"../day01\" }\nday03 = { path = \"../day03\" }\n\n[[bin]]\nname = \"day01\"\npath = \"fuzz_targets/day01.rs\"\n\n[[bin]]\nname = \"day03\"\npath = \"fuzz_targets/day03.rs\"\n\n[[bin]]\nname = \"day01_solve\"\npath = \"fuzz_targets/day01_solve.rs\"\n\n[[bin]]\nname = \"day03_solve\"\npath = \"fuzz_targets/day03_solve.rs\"\n";
+    const REGISTRY: &str = "pub const DAYS: &[Day] = &[\n    Day {\n        day: 1,\n        parts: &[day01::part1, day01::part2],\n    },\n    Day {\n        day: 3,\n        parts: &[day03::part1],\n    },\n];\n";
+
+    fn workspace() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir_all(root.path().join("aoc/src")).unwrap();
+        fs::create_dir_all(root.path().join("day01")).unwrap();
+        fs::create_dir_all(root.path().join("fuzz")).unwrap();
+        fs::write(root.path().join("Cargo.toml"), MANIFEST).unwrap();
+        fs::write(root.path().join("aoc/Cargo.toml"), RUNNER_MANIFEST).unwrap();
+        fs::write(root.path().join("aoc/src/days.rs"), REGISTRY).unwrap();
+        fs::write(root.path().join("fuzz/Cargo.toml"), FUZZ_MANIFEST).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_new_day_registers_crate() {
+        let root = workspace();
+        let dir = new_day(root.path(), 2, 2023).unwrap();
+
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("https://adventofcode.com/2023/day/2\n"));
+        assert!(lib.contains("Input::new(2, contents)"));
+        assert!(lib.contains("pub mod generator;\n"));
+        assert!(dir.join("src/generator.rs").exists());
+        assert!(dir.join("example.txt").exists());
+
+        let manifest = fs::read_to_string(root.path().join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("    \"day01\",\n    \"day02\",\n    \"day03\",\n"));
+        let runner = fs::read_to_string(root.path().join("aoc/Cargo.toml")).unwrap();
+        assert!(runner.contains("day01 = { path = \"../day01\" }\nday02 = { path = \"../day02\" }\nday03"));
+        let registry = fs::read_to_string(root.path().join("aoc/src/days.rs")).unwrap();
+        assert!(registry.contains("    },\n    Day {\n        day: 2,\n        parts: &[day02::part1, day02::part2],\n        generate: Some(day02::generator::generate),\n    },\n    Day {\n        day: 3,"));
+
+        let fuzz = fs::read_to_string(root.path().join("fuzz/Cargo.toml")).unwrap();
+        assert!(fuzz.contains("day01 = { path = \"../day01\" }\nday02 = { path = \"../day02\" }\nday03"));
+        assert!(fuzz.contains("fuzz_targets/day01.rs\"\n\n[[bin]]\nname = \"day02\"\npath = \"fuzz_targets/day02.rs\""));
+        assert!(fuzz.contains("\n\n[[bin]]\nname = \"day02_solve\"\npath = \"fuzz_targets/day02_solve.rs\"\ntest = false\ndoc = false\nbench = false\n\n[[bin]]\nname = \"day03_solve\""));
+        let target = root.path().join("fuzz/fuzz_targets/day02_solve.rs");
+        assert!(fs::read_to_string(target).unwrap().contains("assert_solves(day02::parse, &[day02::part1, day02::part2]"));
+    }
+
+    #[test]
+    fn test_new_day_appends_last_day() {
+        let root = workspace();
+        new_day(root.path(), 25, 2024).unwrap();
+        let registry = fs::read_to_string(root.path().join("aoc/src/days.rs")).unwrap();
+        assert!(registry.ends_with("        parts: &[day25::part1, day25::part2],\n        generate: Some(day25::generator::generate),\n    },\n];\n"));
+        let fuzz = fs::read_to_string(root.path().join("fuzz/Cargo.toml")).unwrap();
+        assert!(fuzz.ends_with("day03_solve.rs\"\n\n[[bin]]\nname = \"day25_solve\"\npath = \"fuzz_targets/day25_solve.rs\"\ntest = false\ndoc = false\nbench = false\n"));
+    }
+
+    #[test]
+    fn test_new_day_refuses_existing_day() {
+        let root = workspace();
+        let err = new_day(root.path(), 1, 2024).unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+        assert_eq!(fs::read_to_string(root.path().join("Cargo.toml")).unwrap(), MANIFEST);
+    }
+
+    #[test]
+    fn test_new_day_refuses_registered_member() {
+        let root = workspace();
+        assert!(new_day(root.path(), 3, 2024).is_err());
+        assert!(!root.path().join("day03").exists());
+    }
+}
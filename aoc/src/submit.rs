@@ -0,0 +1,349 @@
+use crate::client::Client;
+use anyhow::{bail, Context, Result};
+use common::Answer;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use toml::{Table, Value};
+
+/*
+`aoc submit`: posts an answer and remembers what the server said about it.
+
+Every reply is recorded in `guesses.toml` in the year's cache directory:
+
+    [day01.part1]
+    wrong = ["1234", "1300"]
+    too_low = 1234
+    too_high = 1300
+
+An answer that was already rejected, or that lies outside the bounds learnt from "too high" and
+"too low" replies, is refused locally instead of being sent again.
+*/
+
+pub const GUESSES_FILE: &str = "guesses.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// What the server replied to a submitted answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong(Option<Hint>),
+    /// An answer was submitted too recently; retry after this long.
+    Wait(Duration),
+    /// The part is already solved or still locked.
+    WrongLevel,
+    /// A reply the parser does not know, kept verbatim.
+    Unknown(String),
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer"),
+            Verdict::Wrong(Some(Hint::TooHigh)) => write!(f, "Wrong answer, too high"),
+            Verdict::Wrong(Some(Hint::TooLow)) => write!(f, "Wrong answer, too low"),
+            Verdict::Wrong(None) => write!(f, "Wrong answer"),
+            Verdict::Wait(wait) => write!(f, "Answered too recently, wait {}s", wait.as_secs()),
+            Verdict::WrongLevel => write!(f, "Not the current level; already solved or locked"),
+            Verdict::Unknown(text) => write!(f, "Unexpected reply: {}", text),
+        }
+    }
+}
+
+/// Interprets the HTML the server sends back after a submission.
+pub fn parse_reply(html: &str) -> Verdict {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Verdict::Wrong(hint)
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::Wait(parse_wait(&text).unwrap_or(Duration::from_secs(60)))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown(text)
+    }
+}
+
+/// Text of the `<article>` element, or of the whole page, with tags and extra spaces removed.
+fn article_text(html: &str) -> String {
+    let body = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in body.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses "You have 1m 23s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    let mut seconds = 0;
+    for part in text[start..end].split_whitespace() {
+        let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// What is known about the answers of one part.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Guesses {
+    pub wrong: Vec<String>,
+    /// Highest answer the server called too low.
+    pub too_low: Option<u64>,
+    /// Lowest answer the server called too high.
+    pub too_high: Option<u64>,
+    pub correct: Option<String>,
+}
+
+impl Guesses {
+    /// Why `answer` must not be sent, if it is already known to be pointless.
+    pub fn refusal(&self, answer: &Answer) -> Option<String> {
+        let text = answer.to_string();
+        if let Some(correct) = &self.correct {
+            return Some(if *correct == text {
+                format!("{} was already accepted", text)
+            } else {
+                format!("This part was already solved with {}", correct)
+            });
+        }
+        if self.wrong.contains(&text) {
+            return Some(format!("{} was already rejected", text));
+        }
+        if let Answer::Number(n) = answer {
+            if let Some(low) = self.too_low.filter(|&low| *n <= low) {
+                return Some(format!("{} is not above {}, which was too low", n, low));
+            }
+            if let Some(high) = self.too_high.filter(|&high| *n >= high) {
+                return Some(format!("{} is not below {}, which was too high", n, high));
+            }
+        }
+        None
+    }
+
+    /// Learns from the server's verdict on `answer`.
+    pub fn record(&mut self, answer: &Answer, verdict: &Verdict) {
+        let text = answer.to_string();
+        match verdict {
+            Verdict::Correct => self.correct = Some(text),
+            Verdict::Wrong(hint) => {
+                if let (Answer::Number(n), Some(hint)) = (answer, hint) {
+                    match hint {
+                        Hint::TooHigh => self.too_high = Some(self.too_high.map_or(*n, |h| h.min(*n))),
+                        Hint::TooLow => self.too_low = Some(self.too_low.map_or(*n, |l| l.max(*n))),
+                    }
+                }
+                if !self.wrong.contains(&text) {
+                    self.wrong.push(text);
+                }
+            }
+            Verdict::Wait(_) | Verdict::WrongLevel | Verdict::Unknown(_) => {}
+        }
+    }
+}
+
+/// The guesses of every day and part, backed by a TOML file.
+pub struct History {
+    path: PathBuf,
+    table: Table,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self> {
+        let table = if path.exists() {
+            let contents = fs::read_to_string(path)?;
+            contents
+                .parse()
+                .with_context(|| format!("Invalid guess history {}", path.display()))?
+        } else {
+            Table::new()
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            table,
+        })
+    }
+
+    pub fn guesses(&self, day: u8, part: u8) -> Guesses {
+        let Some(Value::Table(entry)) = self
+            .table
+            .get(&format!("day{:02}", day))
+            .and_then(|parts| parts.get(format!("part{}", part)))
+        else {
+            return Guesses::default();
+        };
+        let bound = |key: &str| {
+            entry
+                .get(key)
+                .and_then(Value::as_integer)
+                .and_then(|n| u64::try_from(n).ok())
+        };
+        Guesses {
+            wrong: entry
+                .get("wrong")
+                .and_then(Value::as_array)
+                .map(|wrong| wrong.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+                .unwrap_or_default(),
+            too_low: bound("too_low"),
+            too_high: bound("too_high"),
+            correct: entry.get("correct").and_then(Value::as_str).map(String::from),
+        }
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, guesses: &Guesses) {
+        let mut entry = Table::new();
+        if !guesses.wrong.is_empty() {
+            let wrong = guesses.wrong.iter().cloned().map(Value::String).collect();
+            entry.insert("wrong".to_string(), Value::Array(wrong));
+        }
+        for (key, bound) in [("too_low", guesses.too_low), ("too_high", guesses.too_high)] {
+            if let Some(n) = bound.and_then(|n| i64::try_from(n).ok()) {
+                entry.insert(key.to_string(), Value::Integer(n));
+            }
+        }
+        if let Some(correct) = &guesses.correct {
+            entry.insert("correct".to_string(), Value::String(correct.clone()));
+        }
+
+        let day = self
+            .table
+            .entry(format!("day{:02}", day))
+            .or_insert_with(|| Value::Table(Table::new()));
+        if let Value::Table(day) = day {
+            day.insert(format!("part{}", part), Value::Table(entry));
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, toml::to_string(&self.table)?)
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+}
+
+/// Sends `answer` unless the history already rules it out, then records the verdict.
+pub fn submit_answer(
+    client: &Client,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &Answer,
+) -> Result<Verdict> {
+    let mut guesses = history.guesses(day, part);
+    if let Some(reason) = guesses.refusal(answer) {
+        bail!("Not submitting: {}", reason);
+    }
+
+    let verdict = parse_reply(&client.submit(year, day, part, &answer.to_string())?);
+    guesses.record(answer, &verdict);
+    history.set(day, part, &guesses);
+    history.save()?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::{client, serve};
+
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article></main>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+
+    #[test]
+    fn test_parse_reply() {
+        assert_eq!(parse_reply(TOO_HIGH), Verdict::Wrong(Some(Hint::TooHigh)));
+        assert_eq!(parse_reply(TOO_RECENT), Verdict::Wait(Duration::from_secs(83)));
+        assert_eq!(
+            parse_reply("<article><p>That's the right answer!  You are one gold star closer.</p></article>"),
+            Verdict::Correct
+        );
+        assert_eq!(
+            parse_reply("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"),
+            Verdict::WrongLevel
+        );
+    }
+
+    #[test]
+    fn test_guesses_refuse_known_wrong_and_out_of_bounds() {
+        let mut guesses = Guesses::default();
+        guesses.record(&Answer::Number(100), &Verdict::Wrong(Some(Hint::TooHigh)));
+        guesses.record(&Answer::Number(10), &Verdict::Wrong(Some(Hint::TooLow)));
+        guesses.record(&Answer::Number(50), &Verdict::Wrong(None));
+
+        assert!(guesses.refusal(&Answer::Number(50)).is_some());
+        assert!(guesses.refusal(&Answer::Number(120)).is_some());
+        assert!(guesses.refusal(&Answer::Number(10)).is_some());
+        assert_eq!(guesses.refusal(&Answer::Number(51)), None);
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(GUESSES_FILE);
+        let guesses = Guesses {
+            wrong: vec!["ab,cd".to_string(), "7".to_string()],
+            too_low: Some(7),
+            too_high: None,
+            correct: None,
+        };
+
+        let mut history = History::load(&path).unwrap();
+        history.set(23, 2, &guesses);
+        history.save().unwrap();
+        assert_eq!(History::load(&path).unwrap().guesses(23, 2), guesses);
+        assert_eq!(History::load(&path).unwrap().guesses(23, 1), Guesses::default());
+    }
+
+    #[test]
+    fn test_submit_answer_never_resends_wrong_answer() {
+        let dir = tempfile::tempdir().unwrap();
+        let (base_url, seen) = serve(vec![(200, TOO_HIGH)]);
+        let client = client(&base_url, dir.path());
+        let mut history = History::load(&dir.path().join(GUESSES_FILE)).unwrap();
+
+        let verdict = submit_answer(&client, &mut history, 2024, 1, 2, &Answer::Number(42)).unwrap();
+        assert_eq!(verdict, Verdict::Wrong(Some(Hint::TooHigh)));
+        let request = seen.recv().unwrap();
+        assert_eq!((request.method.as_str(), request.url.as_str()), ("POST", "/2024/day/1/answer"));
+        assert_eq!(request.body, "level=2&answer=42");
+
+        // Refused locally: the mock would not answer a second request.
+        let mut history = History::load(&dir.path().join(GUESSES_FILE)).unwrap();
+        let err = submit_answer(&client, &mut history, 2024, 1, 2, &Answer::Number(43)).unwrap_err();
+        assert!(err.to_string().contains("too high"));
+    }
+}
@@ -0,0 +1,300 @@
+use crate::answers;
+use crate::report::{PartResult, Status};
+use crate::runner;
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/*
+`aoc watch`: rebuilds a day whenever its sources change and re-runs both parts on its examples and
+its input, next to the answers recorded for them in answers.toml.
+
+Changes are found by polling modification times, which needs nothing from the platform. The parts
+run as the day's own release binaries, so only the day and what it depends on are recompiled.
+*/
+
+/// How often the watched files are scanned.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Editors often write a file in several steps; wait this long for them to finish.
+const SETTLE_TIME: Duration = Duration::from_millis(100);
+
+/// Modification time and size of every file under some roots.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, (SystemTime, u64)>);
+
+impl Snapshot {
+    /// Scans `roots`, files or directories walked recursively; missing roots are skipped.
+    pub fn scan(roots: &[PathBuf]) -> Self {
+        let mut snapshot = Snapshot::default();
+        let mut pending: Vec<PathBuf> = roots.to_vec();
+        while let Some(path) = pending.pop() {
+            let Ok(metadata) = fs::metadata(&path) else {
+                continue;
+            };
+            if metadata.is_dir() {
+                let entries = fs::read_dir(&path).into_iter().flatten().flatten();
+                pending.extend(entries.map(|entry| entry.path()));
+            } else if let Ok(modified) = metadata.modified() {
+                snapshot.0.insert(path, (modified, metadata.len()));
+            }
+        }
+        snapshot
+    }
+
+    /// Files added, removed or modified in `newer`.
+    pub fn changes(&self, newer: &Snapshot) -> Vec<PathBuf> {
+        let removed = self.0.keys().filter(|path| !newer.0.contains_key(*path));
+        let touched = newer.0.iter().filter(|(path, stamp)| self.0.get(*path) != Some(stamp));
+        let mut changes: Vec<PathBuf> = removed.chain(touched.map(|(path, _)| path)).cloned().collect();
+        changes.sort();
+        changes
+    }
+}
+
+/// Whether a change to `path` is new input rather than new code.
+fn is_input(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension == "txt")
+}
+
+/// Everything a run of `day` depends on: the day itself, the shared crates, the cached input and
+/// the recorded answers.
+fn watched_paths(day: u8) -> Vec<PathBuf> {
+    let workspace = common::workspace_dir();
+    vec![
+        common::day_dir(day),
+        workspace.join("common/src"),
+        workspace.join("grid/src"),
+        workspace.join("search/src"),
+        common::cached_input_path(common::YEAR, day),
+        answers::default_path(),
+    ]
+}
+
+/// The inputs to run a day on, by name: its example files, the other files it has answers for,
+/// and its input.
+pub fn inputs(dir: &Path, known: &[&str], input: &Path) -> Vec<(String, PathBuf)> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name.starts_with("example") && name.ends_with(".txt"))
+        .chain(known.iter().filter(|name| dir.join(name).is_file()).map(|name| name.to_string()))
+        .filter(|name| name != common::INPUT_FILE)
+        .collect();
+    names.sort();
+    names.dedup();
+
+    let mut inputs: Vec<(String, PathBuf)> = names.into_iter().map(|name| (name.clone(), dir.join(name))).collect();
+    if input.is_file() {
+        inputs.push((common::INPUT_FILE.to_string(), input.to_path_buf()));
+    }
+    inputs
+}
+
+/// The executables of the parts of `day` among cargo's `--message-format json` messages.
+pub fn executables(messages: &str, day: u8) -> Vec<(u8, PathBuf)> {
+    let mut executables: Vec<(u8, PathBuf)> = messages
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter(|message| message["reason"] == "compiler-artifact")
+        .filter_map(|message| {
+            let name = message["target"]["name"].as_str()?;
+            let part = name.strip_prefix(&format!("day{:02}-part", day))?.parse().ok()?;
+            Some((part, PathBuf::from(message["executable"].as_str()?)))
+        })
+        .collect();
+    executables.sort();
+    executables
+}
+
+/// Builds the release binaries of `day`, letting cargo report errors on stderr. None when the
+/// build failed.
+fn build(day: u8) -> Result<Option<Vec<(u8, PathBuf)>>> {
+    let output = Command::new(std::env::var_os("CARGO").unwrap_or("cargo".into()))
+        .args(["build", "--release", "--bins", "--message-format", "json-render-diagnostics", "-p"])
+        .arg(format!("day{:02}", day))
+        .current_dir(common::workspace_dir())
+        .stderr(Stdio::inherit())
+        .output()
+        .context("Failed to run cargo")?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+    Ok(Some(executables(&String::from_utf8_lossy(&output.stdout), day)))
+}
+
+/// One line of the watch table, in red when the part went wrong and in green when it matched.
+fn render(input: &str, result: &PartResult, expected: Option<&common::Answer>, color: bool) -> String {
+    let expected = expected.map(|answer| answer.to_string()).unwrap_or_default();
+    // The expected answer has its own column.
+    let answer = match &result.record {
+        Some(record) => record.answer.to_string(),
+        None => result.detail(),
+    };
+    let line = format!(
+        "{:<16} {:<5} {:<7} {:>12}  {:<24} {}",
+        input,
+        result.part,
+        result.status.label(),
+        result.time().map(crate::readme::format_runtime).unwrap_or_default(),
+        answer,
+        expected
+    );
+    match &result.status {
+        _ if !color => line,
+        Status::Pass => format!("\x1b[32m{}\x1b[0m", line),
+        status if status.is_failure() => format!("\x1b[31m{}\x1b[0m", line),
+        _ => line,
+    }
+}
+
+/// Builds and runs `day` again after every change, until interrupted.
+pub fn watch(day: u8, timeout: Duration) -> Result<()> {
+    let roots = watched_paths(day);
+    let color = std::io::stdout().is_terminal();
+    let mut snapshot = Snapshot::scan(&roots);
+    let mut changes: Vec<PathBuf> = Vec::new();
+    let mut executables: Option<Vec<(u8, PathBuf)>> = None;
+
+    loop {
+        if color {
+            // Clear the screen, so the latest results are all there is.
+            print!("\x1b[2J\x1b[H");
+        }
+        match changes.as_slice() {
+            [] => println!("Day {:02}", day),
+            [path] => println!("Day {:02}: {} changed", day, path.display()),
+            paths => println!("Day {:02}: {} files changed", day, paths.len()),
+        }
+
+        if executables.is_none() || changes.iter().any(|path| !is_input(path)) {
+            executables = build(day)?;
+        }
+        match &executables {
+            None => println!("\nThe build failed"),
+            Some(executables) => {
+                // A half-edited answers file shouldn't end the session.
+                let known = answers::load_known().unwrap_or_else(|err| {
+                    println!("{:#}", err);
+                    Vec::new()
+                });
+                let names: Vec<&str> = known.iter().filter(|e| e.day == day).map(|e| e.input.as_str()).collect();
+                let inputs = inputs(&common::day_dir(day), &names, &common::input_path(day));
+                let tasks: Vec<(&str, &Path, u8, &Path)> = inputs
+                    .iter()
+                    .flat_map(|(name, path)| {
+                        executables.iter().map(move |(part, exe)| (name.as_str(), path.as_path(), *part, exe.as_path()))
+                    })
+                    .collect();
+
+                println!("\nInput            Part  Status          Time  Answer                   Expected");
+                let results = runner::parallel_map(&tasks, runner::default_jobs(), |&(_, path, part, exe)| {
+                    let mut command = Command::new(exe);
+                    command.args(["--format", "json"]).arg(path);
+                    let expected = answers::lookup(&known, day, part, path);
+                    (runner::run_part(&mut command, day, part, expected, timeout), expected)
+                });
+                for ((name, ..), (result, expected)) in tasks.iter().zip(&results) {
+                    println!("{}", render(name, result, *expected, color));
+                }
+            }
+        }
+        println!("\nWatching for changes, press Ctrl-C to stop");
+
+        changes = loop {
+            thread::sleep(POLL_INTERVAL);
+            let newer = Snapshot::scan(&roots);
+            if newer != snapshot {
+                thread::sleep(SETTLE_TIME);
+                let settled = Snapshot::scan(&roots);
+                break std::mem::replace(&mut snapshot, settled).changes(&snapshot);
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Answer, Record};
+
+    #[test]
+    fn test_snapshot_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir(&src).unwrap();
+        fs::write(src.join("lib.rs"), "fn main() {}").unwrap();
+        fs::write(dir.path().join("example.txt"), "1 2").unwrap();
+        let roots = [dir.path().to_path_buf(), dir.path().join("missing.txt")];
+        let before = Snapshot::scan(&roots);
+        assert_eq!(before.0.len(), 2);
+        assert!(before.changes(&Snapshot::scan(&roots)).is_empty());
+
+        fs::write(src.join("lib.rs"), "fn main() { todo!() }").unwrap();
+        fs::remove_file(dir.path().join("example.txt")).unwrap();
+        fs::write(dir.path().join("example2.txt"), "3 4").unwrap();
+        let changes = before.changes(&Snapshot::scan(&roots));
+        assert_eq!(
+            changes,
+            vec![dir.path().join("example.txt"), dir.path().join("example2.txt"), src.join("lib.rs")]
+        );
+        assert!(is_input(&changes[0]) && !is_input(&changes[2]));
+    }
+
+    #[test]
+    fn test_inputs() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["example2.txt", "example.txt", "ninthprime", "notes.txt", "input.txt"] {
+            fs::write(dir.path().join(name), "").unwrap();
+        }
+        let input = dir.path().join("input.txt");
+        let names: Vec<String> = inputs(dir.path(), &["ninthprime", "missing", "input.txt"], &input)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, ["example.txt", "example2.txt", "ninthprime", "input.txt"]);
+    }
+
+    #[test]
+    fn test_executables() {
+        let messages = [
+            r#"{"reason":"compiler-artifact","target":{"name":"common"},"executable":null}"#,
+            r#"{"reason":"compiler-artifact","target":{"name":"day07-part2"},"executable":"/t/day07-part2"}"#,
+            r#"{"reason":"compiler-artifact","target":{"name":"day07-part1"},"executable":"/t/day07-part1"}"#,
+            r#"{"reason":"build-finished","success":true}"#,
+        ]
+        .join("\n");
+        assert_eq!(
+            executables(&messages, 7),
+            vec![(1, PathBuf::from("/t/day07-part1")), (2, PathBuf::from("/t/day07-part2"))]
+        );
+        assert!(executables(&messages, 8).is_empty());
+    }
+
+    #[test]
+    fn test_render_highlights_mismatch() {
+        let result = PartResult {
+            record: Some(Record {
+                day: 1,
+                part: 1,
+                answer: Answer::Number(12),
+                parse_ns: 0,
+                solve_ns: 62_000,
+            }),
+            ..PartResult::new(1, 1, Status::Fail(Answer::Number(11)))
+        };
+        let expected = Answer::Number(11);
+        assert_eq!(
+            render("example.txt", &result, Some(&expected), false),
+            "example.txt      1     FAIL           62 µs  12                       11"
+        );
+        assert!(render("example.txt", &result, Some(&expected), true).starts_with("\x1b[31m"));
+    }
+}
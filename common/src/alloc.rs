@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+
+/*
+Allocation accounting for the measurements in `bench`.
+
+`CountingAllocator` wraps the system allocator and counts every allocation of the process. It is
+opt-in: a binary installs it with `#[global_allocator]`, as the runner does with its
+`count-allocations` feature, and without it `Counters::now` returns None and measurements carry no
+memory figures. The counters are global, so allocations of other threads during a measurement
+count too; the solutions run on one thread.
+*/
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting allocations, the bytes they request and the heap in use.
+/// A `realloc` counts as a new allocation of its new size.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn allocated(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size as u64, Ordering::Relaxed);
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: every call is forwarded to the system allocator unchanged; only counters are updated.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        INSTALLED.store(true, Ordering::Relaxed);
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        INSTALLED.store(true, Ordering::Relaxed);
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            Self::allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Allocations during one phase of a measurement.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// Most heap in use at once, above what was in use when the solver was called.
+    pub peak_bytes: u64,
+}
+
+/// A reading of the counters, from which the allocations since can be taken.
+#[derive(Debug, Clone, Copy)]
+pub struct Counters {
+    allocations: u64,
+    bytes: u64,
+    current: usize,
+}
+
+impl Counters {
+    /// The counters now, with the peak lowered to the heap in use so that `since` sees the peak
+    /// from here on. None unless the counting allocator is installed.
+    pub fn now() -> Option<Self> {
+        if !INSTALLED.load(Ordering::Relaxed) {
+            return None;
+        }
+        let current = reset_peak();
+        Some(Self {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            bytes: BYTES.load(Ordering::Relaxed),
+            current,
+        })
+    }
+
+    /// Allocations since this reading, with the peak taken above the heap in use at `base`.
+    pub fn since(&self, base: &Counters) -> AllocStats {
+        AllocStats {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - self.allocations,
+            bytes: BYTES.load(Ordering::Relaxed) - self.bytes,
+            peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(base.current) as u64,
+        }
+    }
+}
+
+/// Lowers the peak to the heap in use, so that it is taken from here on, and returns that.
+pub fn reset_peak() -> usize {
+    let current = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(current, Ordering::Relaxed);
+    current
+}
+
+/// Whether this process counts its allocations.
+pub fn is_installed() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
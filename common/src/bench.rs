@@ -0,0 +1,239 @@
+use crate::alloc::{self, AllocStats, Counters};
+use crate::{Answer, Solver};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+use tracing::info_span;
+
+/*
+Timing of solutions, shared by `aoc bench` and the per-day `cargo bench` targets.
+
+A solver is a single `fn(&str)`, so days report how long their parser ran by wrapping it in
+`timed_parse`; the rest of the call counts as solving. Allocations are split the same way when the
+counting allocator of `alloc` is installed.
+*/
+
+thread_local! {
+    static PARSE_TIME: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+    static PARSE_ALLOC: Cell<Option<AllocStats>> = const { Cell::new(None) };
+    /// Allocation counters when the current measurement began.
+    static RUN_START: Cell<Option<Counters>> = const { Cell::new(None) };
+}
+
+/// Runs a day's parser and accounts its time and allocations to the parse phase of the current
+/// measurement.
+pub fn timed_parse<T>(parse: impl FnOnce() -> T) -> T {
+    let _span = info_span!("parse").entered();
+    let counters = Counters::now();
+    let start = Instant::now();
+    let parsed = parse();
+    PARSE_TIME.with(|time| time.set(time.get() + start.elapsed()));
+    if let Some(counters) = counters {
+        let base = RUN_START.get().unwrap_or(counters);
+        let stats = counters.since(&base);
+        PARSE_ALLOC.set(Some(match PARSE_ALLOC.get() {
+            Some(before) => AllocStats {
+                allocations: before.allocations + stats.allocations,
+                bytes: before.bytes + stats.bytes,
+                peak_bytes: before.peak_bytes.max(stats.peak_bytes),
+            },
+            None => stats,
+        }));
+        // Solving starts a new peak.
+        alloc::reset_peak();
+    }
+    parsed
+}
+
+fn take_parse_time() -> Duration {
+    PARSE_TIME.with(|time| time.replace(Duration::ZERO))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    /// Untimed runs before measuring, to warm caches and the allocator.
+    pub warmup: u32,
+    pub iterations: u32,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 20,
+        }
+    }
+}
+
+/// Summary of the samples of one phase, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut ns: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        ns.sort_unstable();
+        if ns.is_empty() {
+            return Self {
+                median_ns: 0,
+                p95_ns: 0,
+                min_ns: 0,
+                max_ns: 0,
+            };
+        }
+        let median_ns = if ns.len().is_multiple_of(2) {
+            (ns[ns.len() / 2 - 1] + ns[ns.len() / 2]) / 2
+        } else {
+            ns[ns.len() / 2]
+        };
+        // Nearest-rank percentile.
+        let p95_ns = ns[(ns.len() * 95).div_ceil(100) - 1];
+        Self {
+            median_ns,
+            p95_ns,
+            min_ns: ns[0],
+            max_ns: ns[ns.len() - 1],
+        }
+    }
+}
+
+/// Timings of one part of one day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub iterations: u32,
+    pub parse: Stats,
+    pub solve: Stats,
+    pub total: Stats,
+    /// Allocations of the last run, when the counting allocator is installed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Memory>,
+}
+
+/// Allocations of one run of a part, by phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Memory {
+    pub parse: AllocStats,
+    pub solve: AllocStats,
+}
+
+impl Memory {
+    /// Most heap in use at once during the run.
+    pub fn peak_bytes(&self) -> u64 {
+        self.parse.peak_bytes.max(self.solve.peak_bytes)
+    }
+}
+
+/// Result of a single timed call of a solver.
+#[derive(Debug, Clone)]
+pub struct Timed {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+    pub memory: Option<Memory>,
+}
+
+impl Timed {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+/// Calls `solver` once, splitting its time and allocations into parsing and solving.
+pub fn run_once(solver: Solver, contents: &str) -> Result<Timed> {
+    take_parse_time();
+    PARSE_ALLOC.take();
+    let counters = Counters::now();
+    RUN_START.set(counters);
+    let start = Instant::now();
+    let answer = solver(contents);
+    let elapsed = start.elapsed();
+    RUN_START.take();
+    let parse = take_parse_time();
+    let parse_alloc = PARSE_ALLOC.take().unwrap_or_default();
+    let memory = counters.map(|counters| {
+        let total = counters.since(&counters);
+        Memory {
+            parse: parse_alloc,
+            solve: AllocStats {
+                allocations: total.allocations - parse_alloc.allocations,
+                bytes: total.bytes - parse_alloc.bytes,
+                peak_bytes: total.peak_bytes,
+            },
+        }
+    });
+    Ok(Timed {
+        answer: answer?,
+        parse,
+        solve: elapsed.saturating_sub(parse),
+        memory,
+    })
+}
+
+pub fn measure(day: u8, part: u8, solver: Solver, contents: &str, config: BenchConfig) -> Result<Measurement> {
+    for _ in 0..config.warmup {
+        solver(contents)?;
+    }
+
+    let (mut answer, mut memory) = (None, None);
+    let (mut parse, mut solve, mut total) = (Vec::new(), Vec::new(), Vec::new());
+    for _ in 0..config.iterations.max(1) {
+        let timed = run_once(solver, contents)?;
+        parse.push(timed.parse);
+        solve.push(timed.solve);
+        total.push(timed.total());
+        answer = Some(timed.answer);
+        memory = timed.memory;
+    }
+
+    Ok(Measurement {
+        day,
+        part,
+        answer: answer.map(|a| a.to_string()).unwrap_or_default(),
+        iterations: config.iterations.max(1),
+        parse: Stats::from_samples(&parse),
+        solve: Stats::from_samples(&solve),
+        total: Stats::from_samples(&total),
+        memory,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples: Vec<Duration> = (1..=20).map(Duration::from_nanos).collect();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!((stats.median_ns, stats.p95_ns, stats.min_ns, stats.max_ns), (10, 19, 1, 20));
+        assert_eq!(Stats::from_samples(&[Duration::from_nanos(7)]).p95_ns, 7);
+    }
+
+    #[test]
+    fn test_measure_separates_parse_time() {
+        fn solver(contents: &str) -> Result<Answer> {
+            let n: u64 = timed_parse(|| {
+                std::thread::sleep(Duration::from_millis(2));
+                contents.trim().parse()
+            })?;
+            Ok(Answer::Number(n * 2))
+        }
+        let config = BenchConfig {
+            warmup: 1,
+            iterations: 3,
+        };
+        let measurement = measure(1, 1, solver, "21\n", config).unwrap();
+        assert_eq!(measurement.answer, "42");
+        assert!(measurement.parse.min_ns >= 2_000_000);
+        assert!(measurement.solve.median_ns < measurement.parse.median_ns);
+    }
+}
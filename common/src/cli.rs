@@ -0,0 +1,110 @@
+use crate::bench::run_once;
+use crate::trace::TraceArgs;
+use crate::{resolve_input, Answer, Solver};
+use anyhow::Result;
+use clap::{Parser, ValueEnum};
+use serde::{Deserialize, Serialize};
+use std::ffi::OsString;
+use tracing::info_span;
+
+/*
+Command line shared by the per-day binaries:
+
+    dayNN-partN [INPUT] [--format text|json] [--trace pretty|json|chrome] [-v]...
+
+In JSON mode stdout carries nothing but the record of the solved part, so scripts can consume it.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// The answer with a line of prose
+    #[default]
+    Text,
+    /// One JSON object per solved part
+    Json,
+}
+
+/// One solved part, as printed by `--format json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+}
+
+impl Record {
+    /// Solves `part` of `day` once, timing parsing and solving.
+    pub fn solve(day: u8, part: u8, solver: Solver, contents: &str) -> Result<Self> {
+        let _span = info_span!("solve", day, part).entered();
+        let timed = run_once(solver, contents)?;
+        Ok(Self {
+            day,
+            part,
+            answer: timed.answer,
+            parse_ns: timed.parse.as_nanos() as u64,
+            solve_ns: timed.solve.as_nanos() as u64,
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("records always serialize")
+    }
+}
+
+#[derive(Debug, Parser)]
+#[command(about = "Solve one part of an Advent of Code puzzle")]
+pub struct RunOptions {
+    /// Puzzle input file, or `-` for standard input [default: $AOC_INPUT, then the day's input]
+    input: Option<OsString>,
+    /// How to print the answer
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+    #[command(flatten)]
+    trace: TraceArgs,
+}
+
+impl RunOptions {
+    /// Options of the running binary; exits with a usage message on bad arguments.
+    pub fn from_args() -> Self {
+        Self::parse()
+    }
+
+    /// Solves `part` of `day` and prints the answer, after `label` in text mode.
+    pub fn run(self, day: u8, part: u8, solver: Solver, label: &str) -> Result<()> {
+        let _guard = self.trace.init()?;
+        let contents = resolve_input(day, self.input).read()?;
+        let record = Record::solve(day, part, solver, &contents)?;
+        match self.format {
+            Format::Text => println!("{}: {}", label, record.answer),
+            Format::Json => println!("{}", record.to_json()),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_json() {
+        fn solver(contents: &str) -> Result<Answer> {
+            Ok(contents.trim().into())
+        }
+        let record = Record::solve(23, 2, solver, "co,de,ka,ta\n").unwrap();
+        let json: serde_json::Value = serde_json::from_str(&record.to_json()).unwrap();
+        assert_eq!(json["day"], 23);
+        assert_eq!(json["part"], 2);
+        assert_eq!(json["answer"], "co,de,ka,ta");
+        assert!(json["parse_ns"].is_u64() && json["solve_ns"].is_u64());
+
+        let number = Record {
+            answer: Answer::Number(42),
+            ..record
+        };
+        assert!(number.to_json().contains(r#""answer":42"#));
+        assert_eq!(serde_json::from_str::<Record>(&number.to_json()).unwrap(), number);
+    }
+}
@@ -0,0 +1,206 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/*
+Errors shared by every day: reading the input file and parsing its contents.
+*/
+
+pub type ParseResult<T> = std::result::Result<T, AocError>;
+
+#[derive(Debug)]
+pub enum AocError {
+    /// The input file could not be read.
+    Io { path: PathBuf, source: io::Error },
+    /// The input does not have the shape the puzzle describes.
+    Parse {
+        day: u8,
+        /// 1-based line of the offending text.
+        line: usize,
+        /// 1-based column, counted in characters.
+        column: usize,
+        /// Number of characters to underline, at least one.
+        width: usize,
+        message: String,
+        /// The full source line, used to render the caret diagnostic.
+        source_line: String,
+    },
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            AocError::Parse {
+                day,
+                line,
+                column,
+                width,
+                message,
+                source_line,
+            } => {
+                let gutter = " ".repeat(line.to_string().len());
+                writeln!(f, "day {:02}, line {}, column {}: {}", day, line, column, message)?;
+                writeln!(f, "{} |", gutter)?;
+                writeln!(f, "{} | {}", line, source_line)?;
+                write!(
+                    f,
+                    "{} | {}{}",
+                    gutter,
+                    " ".repeat(column - 1),
+                    "^".repeat(*width)
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            AocError::Parse { .. } => None,
+        }
+    }
+}
+
+/// Puzzle input of one day, able to point at the exact text a parser rejected.
+///
+/// Spans handed to [`Input::error`] and [`Input::parse`] must be slices of the
+/// text the `Input` was created from, e.g. the result of `lines()`, `split` or `trim`.
+#[derive(Clone, Copy)]
+pub struct Input<'a> {
+    day: u8,
+    text: &'a str,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(day: u8, text: &'a str) -> Self {
+        Self { day, text }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Error underlining `span` in the input.
+    pub fn error(&self, span: &str, message: impl Into<String>) -> AocError {
+        let text_start = self.text.as_ptr() as usize;
+        let span_start = span.as_ptr() as usize;
+        let offset = if span_start >= text_start && span_start <= text_start + self.text.len() {
+            span_start - text_start
+        } else {
+            self.text.len()
+        };
+        self.error_at(offset, span.chars().count(), message.into())
+    }
+
+    /// Error for input that stops before a required part.
+    pub fn eof_error(&self, message: impl Into<String>) -> AocError {
+        let end = self.text.trim_end().len();
+        self.error_at(end, 1, message.into())
+    }
+
+    /// Parse `span` as a `T`, pointing at it on failure.
+    pub fn parse<T: FromStr>(&self, span: &str) -> ParseResult<T> {
+        span.parse().map_err(|_| {
+            let expected = std::any::type_name::<T>().rsplit("::").next().unwrap_or("value");
+            self.error(span, format!("expected {}, found `{}`", expected, span))
+        })
+    }
+
+    /// Lines of a rectangular grid, rejecting empty input and ragged rows.
+    pub fn grid_lines(&self) -> ParseResult<Vec<&'a str>> {
+        let lines: Vec<&'a str> = self.text.lines().filter(|line| !line.is_empty()).collect();
+        let Some(first) = lines.first() else {
+            return Err(self.eof_error("expected a grid, found empty input"));
+        };
+        let width = first.chars().count();
+        if let Some(line) = lines.iter().find(|line| line.chars().count() != width) {
+            return Err(self.error(
+                line,
+                format!("expected a row of width {}, found width {}", width, line.chars().count()),
+            ));
+        }
+        Ok(lines)
+    }
+
+    /// Paragraphs of the input, split at blank lines, each without its final line break. Works
+    /// with either line ending and with blank lines that hold whitespace.
+    pub fn blocks(&self) -> Vec<&'a str> {
+        let mut blocks = Vec::new();
+        let mut block: Option<(usize, usize)> = None;
+        let mut offset = 0;
+        for line in self.text.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+            if content.trim().is_empty() {
+                blocks.extend(block.take().map(|(start, end)| &self.text[start..end]));
+            } else {
+                let start = block.map_or(offset, |(start, _)| start);
+                block = Some((start, offset + content.len()));
+            }
+            offset += line.len();
+        }
+        blocks.extend(block.map(|(start, end)| &self.text[start..end]));
+        blocks
+    }
+
+    fn error_at(&self, offset: usize, width: usize, message: String) -> AocError {
+        let line_start = self.text[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.text[offset..]
+            .find('\n')
+            .map_or(self.text.len(), |i| offset + i);
+        let source_line = self.text[line_start..line_end].trim_end_matches('\r');
+        AocError::Parse {
+            day: self.day,
+            line: self.text[..line_start].matches('\n').count() + 1,
+            column: self.text[line_start..offset].chars().count() + 1,
+            width: width.max(1),
+            message,
+            source_line: source_line.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_caret_diagnostic() {
+        let text = "p=0,4 v=3,-3\np=6,x v=-1,-3\n";
+        let input = Input::new(14, text);
+        let token = &text.lines().nth(1).unwrap()[4..5];
+        let err = input.parse::<isize>(token).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 14, line 2, column 5: expected isize, found `x`\n  |\n2 | p=6,x v=-1,-3\n  |     ^"
+        );
+    }
+
+    #[test]
+    fn test_grid_lines_rejects_ragged_rows() {
+        let input = Input::new(4, "XMAS\nMAS\nSAMX\n");
+        match input.grid_lines().unwrap_err() {
+            AocError::Parse { line, width, .. } => assert_eq!((line, width), (2, 3)),
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_eof_error() {
+        let input = Input::new(17, "Register A: 729\n\n");
+        match input.eof_error("missing Register B") {
+            AocError::Parse { line, column, .. } => assert_eq!((line, column), (1, 16)),
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_blocks() {
+        let text = "a\r\nb\r\n\r\nc\n \n\n\nd";
+        assert_eq!(Input::new(13, text).blocks(), ["a\r\nb", "c", "d"]);
+        assert!(Input::new(13, "\n\n").blocks().is_empty());
+    }
+}
@@ -0,0 +1,213 @@
+use crate::generator::Rng;
+use crate::Solver;
+use anyhow::Result;
+use std::fs;
+use std::panic;
+use std::path::Path;
+
+/*
+Robustness checks for the parsers and solvers, shared by the days' tests and the cargo-fuzz targets
+in `fuzz/`.
+
+A parser has to turn any text into either data or an error, never a panic, and the parts have to
+answer or fail with an error on anything the parser accepted. `cargo fuzz` hunts for
+counterexamples on nightly; `assert_mutations_parse` is the stable stand-in run by `cargo test`,
+throwing small random edits of an example at a parser. Inputs that once crashed a parser or a part
+are kept as files in the day's `regressions/` directory and replayed by `assert_regressions`.
+`robustness_test!` puts all of these together into each day's `test_robustness`.
+*/
+
+/// A day's parser on its own, as exposed by each day's `parse`.
+pub type Parser = fn(&str) -> Result<()>;
+
+/// The same input as typed out by other tools: Windows line endings, blank lines at the end and
+/// no final newline.
+pub fn variants(contents: &str) -> Vec<(&'static str, String)> {
+    let lf = contents.replace("\r\n", "\n");
+    vec![
+        ("CRLF line endings", lf.replace('\n', "\r\n")),
+        ("trailing blank lines", format!("{}\n\n\n", lf)),
+        ("no final newline", lf.trim_end_matches('\n').to_string()),
+    ]
+}
+
+/// Test helper: panics unless every part answers each variant of `contents` as it answers
+/// `contents` itself.
+pub fn assert_tolerant(parts: &[Solver], contents: &str) {
+    for (part, solver) in (1..).zip(parts) {
+        let expected = solver(contents).unwrap();
+        for (name, variant) in variants(contents) {
+            match solver(&variant) {
+                Ok(answer) => assert_eq!(answer, expected, "part {} with {}", part, name),
+                Err(err) => panic!("part {} with {}: {:#}", part, name, err),
+            }
+        }
+    }
+}
+
+/// Characters worth inserting on top of the input's own: digits and separators, whitespace and
+/// a character that takes more than one byte.
+const EXTRA: &str = "0123456789,:-|+=#. \t\r\n\u{e9}";
+
+/// `contents` with one to four random edits: characters deleted, repeated, replaced or inserted,
+/// a long run of digits inserted, or the tail cut off.
+pub fn mutate(rng: &mut Rng, contents: &str) -> String {
+    let mut chars: Vec<char> = contents.chars().collect();
+    let alphabet: Vec<char> = contents.chars().chain(EXTRA.chars()).collect();
+    for _ in 0..rng.usize(1..=4) {
+        let at = rng.usize(..=chars.len());
+        let pick = alphabet[rng.usize(..alphabet.len())];
+        match rng.u8(..6) {
+            0 if at < chars.len() => {
+                chars.remove(at);
+            }
+            1 if at < chars.len() => chars.insert(at, chars[at]),
+            2 if at < chars.len() => chars[at] = pick,
+            3 => chars.insert(at, pick),
+            4 => {
+                let digits = rng.usize(10..=40);
+                chars.splice(at..at, (0..digits).map(|_| char::from(b'0' + rng.u8(..10))));
+            }
+            _ => chars.truncate(at),
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// Runs `run`, turning a panic into an error that names `what` and shows the input.
+fn unwinding<T>(what: &str, input: &str, run: impl FnOnce() -> T) -> std::result::Result<T, String> {
+    panic::catch_unwind(panic::AssertUnwindSafe(run)).map_err(|panic| {
+        let message = panic
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        format!("{} panicked ({}) on {:?}", what, message, input)
+    })
+}
+
+/// Runs `parse` on `input`, turning a panic into an error that shows the input. Tells whether the
+/// parser accepted the input.
+fn parse_unwinding(parse: Parser, input: &str) -> std::result::Result<bool, String> {
+    unwinding("parser", input, || parse(input).is_ok())
+}
+
+/// Runs every part on `input` when `parse` accepts it, turning the first panic into an error.
+fn solve_unwinding(parse: Parser, parts: &[Solver], input: &str) -> std::result::Result<(), String> {
+    if parse_unwinding(parse, input)? {
+        for (part, solver) in (1..).zip(parts) {
+            unwinding(&format!("part {}", part), input, || {
+                let _ = solver(input);
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Fuzz target body: panics with the part and the input when `parse` or one of `parts` panics on
+/// `input`.
+pub fn assert_solves(parse: Parser, parts: &[Solver], input: &str) {
+    if let Err(err) = solve_unwinding(parse, parts, input) {
+        panic!("{}", err);
+    }
+}
+
+/// Test helper: runs `parse` on `count` mutations of `contents` and panics on the first input the
+/// parser panicked on instead of rejecting it.
+pub fn assert_mutations_parse(parse: Parser, contents: &str, count: usize) {
+    let mut rng = Rng::with_seed(contents.len() as u64);
+    for _ in 0..count {
+        if let Err(err) = parse_unwinding(parse, &mutate(&mut rng, contents)) {
+            panic!("{}", err);
+        }
+    }
+}
+
+/// Test helper: replays every file in `dir`, if there is one, through `parse` and then `parts`,
+/// which may reject it but must not panic.
+pub fn assert_regressions(parse: Parser, parts: &[Solver], dir: impl AsRef<Path>) {
+    let Ok(entries) = fs::read_dir(dir.as_ref()) else {
+        return;
+    };
+    for entry in entries {
+        let path = entry.unwrap().path();
+        let bytes = fs::read(&path).unwrap();
+        // Fuzzers hand the parsers text only, like the targets in `fuzz/`.
+        let Ok(input) = std::str::from_utf8(&bytes) else {
+            continue;
+        };
+        if let Err(err) = solve_unwinding(parse, parts, input) {
+            panic!("{}: {}", path.display(), err);
+        }
+    }
+}
+
+/// Defines a day's `test_robustness`: `parts` must answer the variants of `example` alike, `parse`
+/// must survive mutations of it, and the files in the day's `regressions/` must not crash either.
+/// A leading `#[ignore = "..."]` is passed on to the test, for days that aren't solved yet.
+#[macro_export]
+macro_rules! robustness_test {
+    (#[ignore = $reason:literal] $($args:tt)*) => {
+        $crate::robustness_test!(@define [#[ignore = $reason]] $($args)*);
+    };
+    (@define [$($attr:tt)*] $parse:expr, [$($part:expr),* $(,)?], $example:expr) => {
+        #[test]
+        $($attr)*
+        fn test_robustness() {
+            let parts: &[$crate::Solver] = &[$($part),*];
+            $crate::fuzz::assert_tolerant(parts, $example);
+            $crate::fuzz::assert_mutations_parse($parse, $example, 2000);
+            let regressions = concat!(env!("CARGO_MANIFEST_DIR"), "/regressions");
+            $crate::fuzz::assert_regressions($parse, parts, regressions);
+        }
+    };
+    ($($args:tt)*) => {
+        $crate::robustness_test!(@define [] $($args)*);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    fn lines(contents: &str) -> Result<Answer> {
+        Ok(contents.lines().filter(|line| !line.is_empty()).count().into())
+    }
+
+    fn first_byte(contents: &str) -> Result<()> {
+        anyhow::ensure!(contents.as_bytes()[0] == b'1', "expected a 1");
+        Ok(())
+    }
+
+    #[test]
+    fn test_variants() {
+        let variants = variants("1 2\n3 4\n");
+        assert_eq!(variants[0].1, "1 2\r\n3 4\r\n");
+        assert_eq!(variants[1].1, "1 2\n3 4\n\n\n\n");
+        assert_eq!(variants[2].1, "1 2\n3 4");
+        assert_tolerant(&[lines], "1 2\n3 4\n");
+    }
+
+    #[test]
+    fn test_mutations_find_panics() {
+        let err = parse_unwinding(first_byte, "").unwrap_err();
+        assert!(err.starts_with("parser panicked (index out of bounds"), "{}", err);
+        assert_eq!(parse_unwinding(first_byte, "2"), Ok(false));
+        let result = panic::catch_unwind(|| assert_mutations_parse(first_byte, "1", 100));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_solvers_find_panics() {
+        let parse = |contents: &str| -> Result<()> {
+            anyhow::ensure!(!contents.is_empty(), "expected something");
+            Ok(())
+        };
+        let second_line = |contents: &str| lines(contents.lines().nth(1).unwrap());
+        assert!(solve_unwinding(parse, &[lines, second_line], "").is_ok());
+        assert!(solve_unwinding(parse, &[lines, second_line], "1\n2\n").is_ok());
+        let err = solve_unwinding(parse, &[lines, second_line], "1\n").unwrap_err();
+        assert!(err.starts_with("part 2 panicked (called `Option::unwrap()` on a `None` value)"), "{}", err);
+    }
+}
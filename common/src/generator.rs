@@ -0,0 +1,240 @@
+use crate::bench::run_once;
+use crate::{Answer, Solver};
+use std::collections::VecDeque;
+use std::fmt;
+use std::time::Duration;
+
+pub use fastrand::Rng;
+
+/*
+Synthetic puzzle inputs, for stress tests and edge cases our own inputs don't cover.
+
+Every day has a `generator::generate` that builds a valid input from a seed and a size in the day's own
+unit (digits of a disk map, bits of an adder, ...). Where the construction fixes an answer, e.g. a
+planted clique or planted wire swaps, the generator returns it so `check` can prove the solver
+finds it. Otherwise `check` only proves the input has a solution the solver can reach.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenOptions {
+    /// Scale in the day's unit; `None` picks something close to a real input.
+    pub size: Option<usize>,
+    pub seed: u64,
+}
+
+impl GenOptions {
+    pub fn new(size: usize, seed: u64) -> Self {
+        Self {
+            size: Some(size),
+            seed,
+        }
+    }
+
+    pub fn size_or(&self, default: usize) -> usize {
+        self.size.unwrap_or(default)
+    }
+
+    pub fn rng(&self) -> Rng {
+        Rng::with_seed(self.seed)
+    }
+}
+
+/// A generated input, with the answers its construction guarantees.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub answers: [Option<Answer>; 2],
+}
+
+impl Generated {
+    /// An input whose answers are only known by solving it.
+    pub fn unknown(input: String) -> Self {
+        Self {
+            input,
+            answers: [None, None],
+        }
+    }
+
+    pub fn part1(mut self, answer: impl Into<Answer>) -> Self {
+        self.answers[0] = Some(answer.into());
+        self
+    }
+
+    pub fn part2(mut self, answer: impl Into<Answer>) -> Self {
+        self.answers[1] = Some(answer.into());
+        self
+    }
+}
+
+pub type Generator = fn(&GenOptions) -> Generated;
+
+/// Lines of a map drawn as bytes, one per row.
+pub fn render(rows: &[Vec<u8>]) -> String {
+    rows.iter().map(|row| String::from_utf8_lossy(row) + "\n").collect()
+}
+
+/// A maze of `#` walls and `.` floor with exactly one path between any two floor tiles. Floor
+/// tiles sit at odd coordinates and the border is wall, so `size` is rounded up to odd.
+pub fn perfect_maze(rng: &mut Rng, size: usize) -> Vec<Vec<u8>> {
+    let n = size.max(3) | 1;
+    let mut rows = vec![vec![b'#'; n]; n];
+    let mut stack: Vec<(usize, usize)> = vec![(1, 1)];
+    rows[1][1] = b'.';
+    while let Some(&(x, y)) = stack.last() {
+        let unvisited: Vec<(usize, usize)> = [(0, 2), (2, 0), (0, -2), (-2, 0)]
+            .into_iter()
+            .filter_map(|(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+            .filter(|&(nx, ny)| nx < n - 1 && ny < n - 1 && rows[ny][nx] == b'#')
+            .collect();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let (nx, ny) = unvisited[rng.usize(..unvisited.len())];
+        rows[(y + ny) / 2][(x + nx) / 2] = b'.';
+        rows[ny][nx] = b'.';
+        stack.push((nx, ny));
+    }
+    rows
+}
+
+/// The tiles from `start` to `end` through the floor of a perfect maze, both ends included.
+pub fn maze_path(rows: &[Vec<u8>], start: (usize, usize), end: (usize, usize)) -> Vec<(usize, usize)> {
+    let mut previous = vec![vec![None; rows[0].len()]; rows.len()];
+    let mut queue = VecDeque::from([start]);
+    while let Some((x, y)) = queue.pop_front() {
+        for (nx, ny) in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+            if rows[ny][nx] == b'.' && previous[ny][nx].is_none() && (nx, ny) != start {
+                previous[ny][nx] = Some((x, y));
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+    let mut path = vec![end];
+    while let Some(&(x, y)) = path.last() {
+        match previous[y][x] {
+            Some(before) => path.push(before),
+            None => break,
+        }
+    }
+    path.reverse();
+    path
+}
+
+/// How one part fared on a generated input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The solver agrees with the planted answer.
+    Confirmed(Answer),
+    /// Solved, but nothing was planted to compare with.
+    Solved(Answer),
+    Wrong { expected: Answer, found: Answer },
+    Failed(String),
+}
+
+impl Outcome {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Outcome::Confirmed(_) | Outcome::Solved(_))
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Confirmed(answer) => write!(f, "{} (as planted)", answer),
+            Outcome::Solved(answer) => write!(f, "{}", answer),
+            Outcome::Wrong { expected, found } => write!(f, "expected {}, found {}", expected, found),
+            Outcome::Failed(err) => write!(f, "failed: {}", err),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub part: u8,
+    pub outcome: Outcome,
+    pub time: Duration,
+}
+
+/// Runs every part on `generated` and compares with the planted answers.
+pub fn check(parts: &[Solver], generated: &Generated) -> Vec<Check> {
+    (1..)
+        .zip(parts)
+        .map(|(part, &solver)| {
+            let expected = generated.answers.get(usize::from(part) - 1).cloned().flatten();
+            match run_once(solver, &generated.input) {
+                Ok(timed) => {
+                    let time = timed.total();
+                    let outcome = match expected {
+                        Some(expected) if expected == timed.answer => Outcome::Confirmed(expected),
+                        Some(expected) => Outcome::Wrong {
+                            expected,
+                            found: timed.answer,
+                        },
+                        None => Outcome::Solved(timed.answer),
+                    };
+                    Check { part, outcome, time }
+                }
+                Err(err) => Check {
+                    part,
+                    outcome: Outcome::Failed(format!("{:#}", err)),
+                    time: Duration::ZERO,
+                },
+            }
+        })
+        .collect()
+}
+
+/// Test helper: generates with a few seeds and panics on the first part that isn't ok.
+pub fn assert_checks(parts: &[Solver], generate: Generator, size: usize) {
+    for seed in 0..3 {
+        let generated = generate(&GenOptions::new(size, seed));
+        for check in check(parts, &generated) {
+            assert!(
+                check.outcome.is_ok(),
+                "seed {}, part {}: {}\n{}",
+                seed,
+                check.part,
+                check.outcome,
+                generated.input
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    fn double(contents: &str) -> Result<Answer> {
+        Ok(Answer::Number(contents.trim().parse::<u64>()? * 2))
+    }
+
+    #[test]
+    fn test_perfect_maze() {
+        let maze = perfect_maze(&mut Rng::with_seed(7), 10);
+        assert_eq!(maze.len(), 11);
+        let floor = maze.iter().flatten().filter(|&&c| c == b'.').count();
+        // A spanning tree of the 5x5 rooms: 25 rooms and 24 passages between them
+        assert_eq!(floor, 25 + 24);
+        assert!(maze[0].iter().chain(&maze[10]).all(|&c| c == b'#'));
+
+        let path = maze_path(&maze, (1, 9), (9, 1));
+        assert_eq!((path[0], path[path.len() - 1]), ((1, 9), (9, 1)));
+        assert!(path.windows(2).all(|step| step[0].0.abs_diff(step[1].0) + step[0].1.abs_diff(step[1].1) == 1));
+    }
+
+    #[test]
+    fn test_check() {
+        let generated = Generated::unknown("21".to_string()).part1(42u64);
+        let checks = check(&[double, double], &generated);
+        assert_eq!(checks[0].outcome, Outcome::Confirmed(Answer::Number(42)));
+        assert_eq!(checks[1].outcome, Outcome::Solved(Answer::Number(42)));
+
+        let wrong = check(&[double], &Generated::unknown("2".to_string()).part1(5u64));
+        assert!(!wrong[0].outcome.is_ok());
+        let failed = check(&[double], &Generated::unknown("x".to_string()));
+        assert!(matches!(failed[0].outcome, Outcome::Failed(_)));
+    }
+}
@@ -0,0 +1,251 @@
+use anyhow::Result;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub mod alloc;
+pub mod bench;
+pub mod cli;
+mod error;
+pub mod fuzz;
+pub mod generator;
+pub mod trace;
+
+pub use bench::timed_parse;
+pub use cli::{Format, Record, RunOptions};
+pub use error::{AocError, Input, ParseResult};
+pub use trace::{TraceArgs, TraceFormat, TraceGuard};
+
+/*
+Shared plumbing for the day crates and the `aoc` runner.
+*/
+
+/// Entry point every day exposes for each of its parts.
+pub type Solver = fn(&str) -> Result<Answer>;
+
+/// The answer to one part of a puzzle, as it is typed into the website.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(u64),
+    /// Answers that are not a single number, e.g. day 17's comma-joined output
+    /// or day 23's password.
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Numbers stay JSON numbers, text answers become strings.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(n) => serializer.serialize_u64(*n),
+            Answer::Text(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+/// Reads back what `Serialize` writes, e.g. the records of `--format json`.
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Number(u64),
+            Text(String),
+        }
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Number(n) => Answer::Number(n),
+            Raw::Text(s) => Answer::Text(s),
+        })
+    }
+}
+
+macro_rules! answer_from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Number(n as u64)
+            }
+        })*
+    };
+}
+
+answer_from_unsigned!(u8, u16, u32, u64, usize);
+
+macro_rules! answer_from_signed {
+    ($($t:ty),*) => {
+        $(impl TryFrom<$t> for Answer {
+            type Error = anyhow::Error;
+
+            fn try_from(n: $t) -> Result<Self> {
+                u64::try_from(n)
+                    .map(Answer::Number)
+                    .map_err(|_| anyhow::anyhow!("Answer {} is negative", n))
+            }
+        })*
+    };
+}
+
+answer_from_signed!(i32, i64);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// Event year of the puzzles in this workspace.
+pub const YEAR: u16 = 2024;
+
+pub const INPUT_FILE: &str = "input.txt";
+pub const EXAMPLE_FILE: &str = "example.txt";
+
+pub fn read_input(file_path: impl AsRef<Path>) -> ParseResult<String> {
+    let path = file_path.as_ref();
+    fs::read_to_string(path).map_err(|source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Root of the workspace, independent of the current working directory.
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("common crate lives inside the workspace")
+        .to_path_buf()
+}
+
+/// Directory of the `dayNN` crate holding that day's inputs.
+pub fn day_dir(day: u8) -> PathBuf {
+    workspace_dir().join(format!("day{:02}", day))
+}
+
+/// Per-year directory of downloaded inputs, `$AOC_CACHE_DIR/<year>` or `.cache/<year>` in the
+/// workspace. It is git-ignored so inputs are never committed.
+pub fn cache_dir(year: u16) -> PathBuf {
+    let root = match std::env::var_os("AOC_CACHE_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => workspace_dir().join(".cache"),
+    };
+    root.join(year.to_string())
+}
+
+/// Where `aoc fetch` stores the input of `day`.
+pub fn cached_input_path(year: u16, day: u8) -> PathBuf {
+    cache_dir(year).join(format!("day{:02}.txt", day))
+}
+
+/// Input file of `day`: a hand-placed `dayNN/input.txt` wins over a downloaded one.
+/// When neither exists the day directory's path is returned so errors name the usual place.
+pub fn input_path(day: u8) -> PathBuf {
+    let local = day_dir(day).join(INPUT_FILE);
+    let cached = cached_input_path(YEAR, day);
+    if !local.exists() && cached.exists() {
+        cached
+    } else {
+        local
+    }
+}
+
+/// Input argument, or `AOC_INPUT` value, that stands for standard input.
+pub const STDIN_ARG: &str = "-";
+
+/// Where the puzzle input of a run comes from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// `-` means standard input, anything else is a file path.
+    pub fn from_arg(arg: impl Into<OsString>) -> Self {
+        let arg = arg.into();
+        if arg == STDIN_ARG {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    pub fn read(&self) -> ParseResult<String> {
+        match self {
+            InputSource::Stdin => io::read_to_string(io::stdin()).map_err(|source| AocError::Io {
+                path: PathBuf::from("<stdin>"),
+                source,
+            }),
+            InputSource::File(path) => read_input(path),
+        }
+    }
+
+    /// A buffered reader of the input, for days that stream inputs too big to hold in memory.
+    pub fn open(&self) -> ParseResult<Box<dyn io::BufRead>> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::File(path) => fs::File::open(path)
+                .map(|file| Box::new(io::BufReader::new(file)) as Box<dyn io::BufRead>)
+                .map_err(|source| AocError::Io {
+                    path: path.clone(),
+                    source,
+                }),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "standard input"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Input of `day`, taken from the first of:
+/// - `arg`, a path or `-` for standard input,
+/// - the `AOC_INPUT` environment variable, read the same way,
+/// - `input_path(day)`, which does not depend on the current directory.
+pub fn resolve_input(day: u8, arg: Option<OsString>) -> InputSource {
+    match arg.or_else(|| std::env::var_os("AOC_INPUT")) {
+        Some(arg) => InputSource::from_arg(arg),
+        None => InputSource::File(input_path(day)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_input() {
+        assert_eq!(resolve_input(5, Some("-".into())), InputSource::Stdin);
+        assert_eq!(
+            resolve_input(5, Some("gen/day05.txt".into())),
+            InputSource::File(PathBuf::from("gen/day05.txt"))
+        );
+        if std::env::var_os("AOC_INPUT").is_none() {
+            let InputSource::File(path) = resolve_input(5, None) else {
+                panic!("expected the day's input file");
+            };
+            assert!(path.is_absolute());
+            assert!(path.ends_with("day05/input.txt") || path.ends_with("2024/day05.txt"));
+        }
+    }
+}
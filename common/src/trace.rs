@@ -0,0 +1,124 @@
+use anyhow::{Context, Result};
+use clap::{ArgAction, Args, ValueEnum};
+use std::path::PathBuf;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, EnvFilter, Layer, Registry};
+
+/*
+Diagnostics of the solutions, recorded with `tracing`.
+
+Every solved part runs in a `solve` span with a nested `parse` span, and days add spans and events
+around their interesting loops. Nothing is recorded unless a subscriber is chosen with `--trace`
+or `-v`. The level follows `-v` (debug) and `-vv` (trace), or `RUST_LOG` when it is set.
+*/
+
+pub const CHROME_TRACE_FILE: &str = "trace.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TraceFormat {
+    /// Human-readable multi-line events on stderr
+    Pretty,
+    /// One JSON object per event on stderr
+    Json,
+    /// A trace file for chrome://tracing or Perfetto
+    Chrome,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct TraceArgs {
+    /// Record spans and events; `-v` alone implies pretty
+    #[arg(long, value_enum, global = true)]
+    pub trace: Option<TraceFormat>,
+    /// Where --trace chrome writes its file
+    #[arg(long, global = true, default_value = CHROME_TRACE_FILE)]
+    pub trace_file: PathBuf,
+    /// Show debugging events; repeat for more detail
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    pub verbose: u8,
+}
+
+/// Keeps the chrome trace open; the file is complete once this is dropped.
+#[must_use]
+pub struct TraceGuard {
+    _chrome: Option<tracing_chrome::FlushGuard>,
+}
+
+impl TraceArgs {
+    /// The subscriber to install, if any.
+    fn format(&self) -> Option<TraceFormat> {
+        match (self.trace, self.verbose) {
+            (Some(format), _) => Some(format),
+            (None, 0) => None,
+            (None, _) => Some(TraceFormat::Pretty),
+        }
+    }
+
+    /// Most detailed level recorded when `RUST_LOG` is not set.
+    fn level(&self) -> &'static str {
+        match self.verbose {
+            0 => "info",
+            1 => "debug",
+            _ => "trace",
+        }
+    }
+
+    /// Installs the chosen subscriber for the rest of the process.
+    pub fn init(&self) -> Result<TraceGuard> {
+        let Some(format) = self.format() else {
+            return Ok(TraceGuard { _chrome: None });
+        };
+        let filter = match std::env::var("RUST_LOG") {
+            Ok(directives) => EnvFilter::try_new(directives).context("Invalid RUST_LOG")?,
+            Err(_) => EnvFilter::new(self.level()),
+        };
+
+        let mut chrome = None;
+        let layer = match format {
+            TraceFormat::Pretty => fmt::layer().pretty().with_writer(std::io::stderr).boxed(),
+            TraceFormat::Json => fmt::layer().json().with_writer(std::io::stderr).boxed(),
+            TraceFormat::Chrome => {
+                let (layer, guard) = tracing_chrome::ChromeLayerBuilder::new()
+                    .file(&self.trace_file)
+                    .include_args(true)
+                    .build();
+                chrome = Some(guard);
+                layer.boxed()
+            }
+        };
+        Registry::default()
+            .with(layer.with_filter(filter))
+            .try_init()
+            .context("A tracing subscriber is already installed")?;
+        Ok(TraceGuard { _chrome: chrome })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        trace: TraceArgs,
+    }
+
+    fn parse(args: &[&str]) -> TraceArgs {
+        Cli::parse_from(std::iter::once("test").chain(args.iter().copied())).trace
+    }
+
+    #[test]
+    fn test_trace_args() {
+        assert_eq!(parse(&[]).format(), None);
+        assert_eq!(parse(&["-v"]).format(), Some(TraceFormat::Pretty));
+        assert_eq!(parse(&["-v"]).level(), "debug");
+
+        let chrome = parse(&["--trace", "chrome", "-vv"]);
+        assert_eq!(chrome.format(), Some(TraceFormat::Chrome));
+        assert_eq!(chrome.level(), "trace");
+        assert_eq!(chrome.trace_file, PathBuf::from(CHROME_TRACE_FILE));
+        assert_eq!(parse(&["--trace", "json"]).level(), "info");
+    }
+}
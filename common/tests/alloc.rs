@@ -0,0 +1,57 @@
+use anyhow::Result;
+use common::alloc::{is_installed, AllocStats, CountingAllocator};
+use common::bench::{measure, run_once, timed_parse, BenchConfig, Memory};
+use common::Answer;
+use std::hint::black_box;
+
+/*
+The counting allocator has to be the global allocator of a whole binary, so it is tested here
+rather than next to the code; the counters are global, so this file holds a single test.
+*/
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Parses into one allocation of 800 bytes, then solves with two more of 8000 and 4000 bytes,
+/// the first while the parsed numbers are still held.
+fn solver(contents: &str) -> Result<Answer> {
+    let numbers = timed_parse(|| {
+        let mut numbers: Vec<u64> = Vec::with_capacity(100);
+        numbers.extend(contents.split(',').map(|n| n.trim().parse::<u64>().unwrap()));
+        numbers
+    });
+    let squares: Vec<u64> = black_box(Vec::with_capacity(1000));
+    drop(squares);
+    let mut sum: Vec<u64> = black_box(Vec::with_capacity(500));
+    sum.push(numbers.iter().sum());
+    Ok(sum[0].into())
+}
+
+#[test]
+fn test_counts_allocations_per_phase() {
+    assert!(is_installed());
+    let expected = Memory {
+        parse: AllocStats {
+            allocations: 1,
+            bytes: 800,
+            peak_bytes: 800,
+        },
+        solve: AllocStats {
+            allocations: 2,
+            bytes: 12_000,
+            peak_bytes: 8800,
+        },
+    };
+
+    let timed = run_once(solver, "1, 2, 3\n").unwrap();
+    assert_eq!(timed.answer, Answer::Number(6));
+    assert_eq!(timed.memory, Some(expected));
+
+    let config = BenchConfig {
+        warmup: 1,
+        iterations: 3,
+    };
+    let measurement = measure(1, 1, solver, "1, 2, 3\n", config).unwrap();
+    assert_eq!(measurement.memory, Some(expected));
+    assert_eq!(expected.peak_bytes(), 8800);
+}
@@ -0,0 +1,28 @@
+use clap::Parser;
+use day01::columns::{parse_columns, render_matrices, render_pairs, Metric};
+use std::ffi::OsString;
+
+/// Location lists with any number of columns, compared pairwise
+#[derive(Parser)]
+struct Args {
+    /// Location lists, or `-` for standard input [default: $AOC_INPUT, then the day's input]
+    input: Option<OsString>,
+    /// Metrics to compare the columns with, separated by commas [default: all]
+    #[arg(long, value_enum, value_delimiter = ',')]
+    metric: Vec<Metric>,
+    /// Print one matrix per metric instead of one table per pair of columns
+    #[arg(long)]
+    matrix: bool,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let metrics = if args.metric.is_empty() { Metric::ALL.to_vec() } else { args.metric };
+    let columns = parse_columns(&common::resolve_input(1, args.input).read()?)?;
+    if args.matrix {
+        print!("{}", render_matrices(&columns, &metrics));
+    } else {
+        print!("{}", render_pairs(&columns, &metrics));
+    }
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anyhow::anyhow;
+use clap::Parser;
+use day01::stream::{totals, Options};
+use std::ffi::OsString;
+
+/// Total distance and similarity score of location lists of any size
+#[derive(Parser)]
+struct Args {
+    /// Location lists, or `-` for standard input [default: $AOC_INPUT, then the day's input]
+    input: Option<OsString>,
+    /// Mebibytes of IDs to sort in memory before spilling sorted runs to disk
+    #[arg(long, default_value_t = 256)]
+    memory_budget: usize,
+    /// Directory for the sorted runs [default: the system's temporary directory]
+    #[arg(long)]
+    spill_dir: Option<std::path::PathBuf>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let memory_budget = args
+        .memory_budget
+        .checked_mul(1 << 20)
+        .ok_or_else(|| anyhow!("--memory-budget of {} MiB is too many bytes", args.memory_budget))?;
+    let options = Options {
+        memory_budget,
+        spill_dir: args.spill_dir,
+    };
+    let totals = totals(common::resolve_input(1, args.input).open()?, &options)?;
+    println!("Total distance: {}", totals.distance);
+    println!("Similarity score: {}", totals.similarity);
+    Ok(())
+}
@@ -0,0 +1,275 @@
+use clap::ValueEnum;
+use common::{Input, ParseResult};
+use std::fmt::{self, Display};
+use std::iter::Peekable;
+
+/*
+Location lists with any number of columns, compared pairwise.
+
+Every line holds the same number of location IDs, at least two. Each pair of columns is compared
+under the chosen metrics: the total distance of part 1, the similarity score of part 2, the Jaccard
+overlap of the IDs the columns contain and the size of their multiset difference. All of them walk
+both columns sorted, so a pair takes linear time after one sort per column.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Metric {
+    /// Sum of the differences of the i-th smallest IDs, as in part 1
+    Distance,
+    /// Sum of each ID times how often it is in both columns, as in part 2
+    Similarity,
+    /// Distinct IDs in both columns over distinct IDs in either
+    Jaccard,
+    /// IDs of the first column left over after removing those of the second, with repeats
+    Difference,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 4] = [Metric::Distance, Metric::Similarity, Metric::Jaccard, Metric::Difference];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Metric::Distance => "Total distance",
+            Metric::Similarity => "Similarity score",
+            Metric::Jaccard => "Jaccard overlap",
+            Metric::Difference => "Multiset difference",
+        }
+    }
+
+    /// Whether comparing `b` with `a` gives the same as comparing `a` with `b`.
+    pub fn is_symmetric(self) -> bool {
+        self != Metric::Difference
+    }
+
+    /// Compares two sorted columns.
+    pub fn compare(self, a: &[u32], b: &[u32]) -> Score {
+        match self {
+            Metric::Distance => Score::Count(a.iter().zip(b).map(|(x, y)| x.abs_diff(*y) as u128).sum()),
+            Metric::Similarity => {
+                Score::Count(merge(a, b).map(|(id, x, y)| id as u128 * x as u128 * y as u128).sum())
+            }
+            Metric::Jaccard => {
+                let (both, either) = merge(a, b).fold((0, 0), |(both, either), (_, x, y)| {
+                    (both + (x > 0 && y > 0) as u64, either + 1)
+                });
+                // Two empty columns are the same set.
+                Score::Ratio(if either == 0 { 1.0 } else { both as f64 / either as f64 })
+            }
+            Metric::Difference => Score::Count(merge(a, b).map(|(_, x, y)| x.saturating_sub(y) as u128).sum()),
+        }
+    }
+}
+
+/// One comparison of two columns.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Score {
+    Count(u128),
+    Ratio(f64),
+}
+
+impl Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Pad the text rather than the number, so table cells line up either way.
+        match self {
+            Score::Count(count) => count.to_string().fmt(f),
+            Score::Ratio(ratio) => format!("{:.4}", ratio).fmt(f),
+        }
+    }
+}
+
+/// The IDs of one sorted column with how often each occurs.
+struct Runs<'a>(Peekable<std::slice::Iter<'a, u32>>);
+
+impl Iterator for Runs<'_> {
+    type Item = (u32, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = *self.0.next()?;
+        let mut count = 1;
+        while self.0.next_if_eq(&&id).is_some() {
+            count += 1;
+        }
+        Some((id, count))
+    }
+}
+
+/// Every ID of two sorted columns, ascending, with how often it occurs in each.
+fn merge<'a>(a: &'a [u32], b: &'a [u32]) -> impl Iterator<Item = (u32, u64, u64)> + 'a {
+    let mut a = Runs(a.iter().peekable()).peekable();
+    let mut b = Runs(b.iter().peekable()).peekable();
+    std::iter::from_fn(move || match (a.peek().copied(), b.peek().copied()) {
+        (Some((x, count)), Some((y, _))) if x < y => a.next().map(|_| (x, count, 0)),
+        (Some((x, _)), Some((y, count))) if y < x => b.next().map(|_| (y, 0, count)),
+        (Some((x, left)), Some((_, right))) => {
+            a.next();
+            b.next();
+            Some((x, left, right))
+        }
+        (Some((x, count)), None) => a.next().map(|_| (x, count, 0)),
+        (None, Some((y, count))) => b.next().map(|_| (y, 0, count)),
+        (None, None) => None,
+    })
+}
+
+/// Parses lines of equally many location IDs into their columns, each sorted.
+pub fn parse_columns(contents: &str) -> ParseResult<Vec<Vec<u32>>> {
+    let input = Input::new(1, contents);
+    let mut columns: Vec<Vec<u32>> = Vec::new();
+    for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if columns.is_empty() {
+            if fields.len() < 2 {
+                return Err(input.error(line, "expected at least two location IDs"));
+            }
+            columns.resize(fields.len(), Vec::new());
+        } else if fields.len() != columns.len() {
+            return Err(input.error(line, format!("expected {} location IDs", columns.len())));
+        }
+        for (column, field) in columns.iter_mut().zip(fields) {
+            column.push(input.parse(field)?);
+        }
+    }
+    for column in &mut columns {
+        column.sort_unstable();
+    }
+    Ok(columns)
+}
+
+/// Every column compared with every other under `metric`, row by row.
+pub fn matrix(columns: &[Vec<u32>], metric: Metric) -> Vec<Vec<Score>> {
+    columns
+        .iter()
+        .map(|a| columns.iter().map(|b| metric.compare(a, b)).collect())
+        .collect()
+}
+
+/// Right-aligns `rows` under `header`, the first column left-aligned.
+fn table(header: Vec<String>, rows: Vec<Vec<String>>) -> String {
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| rows.iter().chain([&header]).map(|row| row[i].chars().count()).max().unwrap_or(0))
+        .collect();
+    [header].iter().chain(&rows).fold(String::new(), |mut text, row| {
+        let cells = row.iter().zip(&widths).enumerate().map(|(i, (cell, &width))| match i {
+            0 => format!("{:<width$}", cell),
+            _ => format!("{:>width$}", cell),
+        });
+        text.push_str(cells.collect::<Vec<_>>().join("  ").trim_end());
+        text.push('\n');
+        text
+    })
+}
+
+/// One table per metric, with a row and a column for every column of the lists. A cell compares
+/// the column of its row with the column of its column.
+pub fn render_matrices(columns: &[Vec<u32>], metrics: &[Metric]) -> String {
+    let names: Vec<String> = (1..=columns.len()).map(|column| column.to_string()).collect();
+    let tables: Vec<String> = metrics
+        .iter()
+        .map(|&metric| {
+            let header = [String::new()].into_iter().chain(names.iter().cloned()).collect();
+            let rows = matrix(columns, metric)
+                .iter()
+                .zip(&names)
+                .map(|(row, name)| [name.clone()].into_iter().chain(row.iter().map(Score::to_string)).collect())
+                .collect();
+            format!("{}\n{}", metric.title(), table(header, rows))
+        })
+        .collect();
+    tables.join("\n")
+}
+
+/// One table per pair of columns, with a row per metric; metrics that depend on the order of the
+/// columns get a row for each order.
+pub fn render_pairs(columns: &[Vec<u32>], metrics: &[Metric]) -> String {
+    let mut tables = Vec::new();
+    for i in 0..columns.len() {
+        for j in i + 1..columns.len() {
+            let (a, b) = (&columns[i], &columns[j]);
+            let mut rows = Vec::new();
+            for &metric in metrics {
+                if metric.is_symmetric() {
+                    rows.push(vec![metric.title().to_string(), metric.compare(a, b).to_string()]);
+                } else {
+                    for (from, to, x, y) in [(i, j, a, b), (j, i, b, a)] {
+                        let title = format!("{} {} - {}", metric.title(), from + 1, to + 1);
+                        rows.push(vec![title, metric.compare(x, y).to_string()]);
+                    }
+                }
+            }
+            let header = vec![format!("Columns {} and {}", i + 1, j + 1), String::new()];
+            tables.push(table(header, rows));
+        }
+    }
+    tables.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generator, part1, part2};
+    use common::generator::GenOptions;
+    use common::Answer;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn count(score: Score) -> u64 {
+        match score {
+            Score::Count(count) => count as u64,
+            Score::Ratio(ratio) => panic!("expected a count, found {}", ratio),
+        }
+    }
+
+    #[test]
+    fn test_two_columns_match_parts() {
+        let columns = parse_columns(EXAMPLE).unwrap();
+        assert_eq!(count(Metric::Distance.compare(&columns[0], &columns[1])), 11);
+        assert_eq!(count(Metric::Similarity.compare(&columns[0], &columns[1])), 31);
+
+        let generated = generator::generate(&GenOptions::new(500, 3));
+        let columns = parse_columns(&generated.input).unwrap();
+        let distance = count(Metric::Distance.compare(&columns[0], &columns[1]));
+        let similarity = count(Metric::Similarity.compare(&columns[0], &columns[1]));
+        assert_eq!(part1(&generated.input).unwrap(), Answer::Number(distance));
+        assert_eq!(part2(&generated.input).unwrap(), Answer::Number(similarity));
+    }
+
+    #[test]
+    fn test_metrics() {
+        // Columns [1, 2, 3, 3] [2, 3, 3, 4] [1, 1, 5, 9].
+        let columns = parse_columns("3 4 1\n1 3 5\n3 2 9\n2 3 1\n").unwrap();
+        assert_eq!(
+            matrix(&columns, Metric::Difference),
+            [[0, 1, 3], [1, 0, 4], [3, 4, 0]].map(|row| row.map(Score::Count))
+        );
+        assert_eq!(Metric::Jaccard.compare(&columns[0], &columns[1]), Score::Ratio(0.5));
+        assert_eq!(Metric::Jaccard.compare(&columns[0], &columns[2]), Score::Ratio(0.2));
+        assert_eq!(Metric::Similarity.compare(&columns[0], &columns[0]), Score::Count(1 + 2 + 3 * 4));
+        assert_eq!(Metric::Distance.compare(&columns[1], &columns[2]), Score::Count(1 + 2 + 2 + 5));
+        assert_eq!(Metric::Jaccard.compare(&[], &[]), Score::Ratio(1.0));
+    }
+
+    #[test]
+    fn test_render() {
+        let columns = parse_columns("3 4 1\n1 3 5\n3 2 9\n2 3 1\n").unwrap();
+        assert_eq!(
+            render_matrices(&columns, &[Metric::Distance, Metric::Jaccard]),
+            "Total distance\n   1   2   3\n1  0   3   9\n2  3   0  10\n3  9  10   0\n\n\
+             Jaccard overlap\n        1       2       3\n1  1.0000  0.5000  0.2000\n\
+             2  0.5000  1.0000  0.0000\n3  0.2000  0.0000  1.0000\n"
+        );
+        let pairs = render_pairs(&columns, &[Metric::Similarity, Metric::Difference]);
+        assert!(pairs.starts_with(
+            "Columns 1 and 2\nSimilarity score           14\n\
+             Multiset difference 1 - 2   1\nMultiset difference 2 - 1   1\n\nColumns 1 and 3\n"
+        ));
+        assert_eq!(pairs.matches("Columns").count(), 3);
+    }
+
+    #[test]
+    fn test_rejects_ragged_lines() {
+        let err = parse_columns("1 2 3\n4 5\n").unwrap_err();
+        assert!(err.to_string().starts_with("day 01, line 2, column 1: expected 3 location IDs"), "{}", err);
+        let err = parse_columns("1\n").unwrap_err();
+        assert!(err.to_string().contains("expected at least two location IDs"));
+    }
+}
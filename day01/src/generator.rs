@@ -0,0 +1,44 @@
+use common::generator::{GenOptions, Generated};
+use std::collections::HashMap;
+
+/*
+`size` lines (1000 by default) of two five-digit location IDs. About half of the right column
+repeats IDs of the left one, so the similarity score is far from zero. Both answers are computed
+directly from the drawn lists.
+*/
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let lines = options.size_or(1000);
+
+    let left: Vec<u64> = (0..lines).map(|_| rng.u64(10_000..100_000)).collect();
+    let right: Vec<u64> = (0..lines)
+        .map(|_| match rng.bool() {
+            true => left[rng.usize(..lines)],
+            false => rng.u64(10_000..100_000),
+        })
+        .collect();
+
+    let input: String = left
+        .iter()
+        .zip(&right)
+        .map(|(lhs, rhs)| format!("{}   {}\n", lhs, rhs))
+        .collect();
+
+    let (mut sorted_left, mut sorted_right) = (left.clone(), right.clone());
+    sorted_left.sort_unstable();
+    sorted_right.sort_unstable();
+    let distance: u64 = sorted_left
+        .iter()
+        .zip(&sorted_right)
+        .map(|(lhs, rhs)| lhs.abs_diff(*rhs))
+        .sum();
+
+    let mut counts: HashMap<u64, u64> = HashMap::new();
+    for &id in &right {
+        *counts.entry(id).or_default() += 1;
+    }
+    let similarity: u64 = left.iter().map(|id| id * counts.get(id).unwrap_or(&0)).sum();
+
+    Generated::unknown(input).part1(distance).part2(similarity)
+}
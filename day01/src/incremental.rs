@@ -0,0 +1,499 @@
+/*
+Location lists under edits: IDs are inserted into and removed from either list, and the total
+distance and the similarity score are kept up to date instead of being recomputed.
+
+The similarity score is a sum over IDs of `id * left * right`, for the number of times the ID is on
+each side, so an edit changes it by `id` times the count on the other side. Finding that count
+takes a binary search, O(log n).
+
+The total distance pairs the i-th smallest IDs of the lists. Summed up, that is the area between
+their counting functions: between consecutive distinct IDs `a < b`, the gap `b - a` is crossed by
+`|level|` pairs, where `level` is the number of left IDs up to `a` minus the number of right IDs.
+An edit at `id` moves the level of every gap above `id` by one, which no known structure follows in
+logarithmic time; it is the dynamic version of minimum-cost matching on a line, and a Fenwick or
+segment tree over the IDs can add to a range of levels but not keep the sum of their absolute
+values. The distinct IDs are therefore kept in blocks of about sqrt(n) entries. A block caches the
+levels of its gaps relative to the blocks before it, sorted with running sums, so the cost of all of
+its gaps at any offset takes a binary search. An edit rebuilds its own block and moves the offset
+of each of the O(sqrt(n)) later blocks, O(sqrt(n) log n) in all. Blocks are split and merged to stay
+within a factor of two of the block size, and everything is regrouped once the number of distinct
+IDs has moved past a factor of four, which amortizes to O(log n) per edit. Both answers are then
+read in constant time.
+*/
+
+/// Smallest block size, below which blocks save nothing over a flat list.
+const MIN_BLOCK: usize = 16;
+
+/// The block size for `ids` distinct IDs.
+fn block_size(ids: usize) -> usize {
+    ids.isqrt().max(MIN_BLOCK)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    /// How an ID on this side moves the levels above it.
+    fn step(self) -> i64 {
+        match self {
+            Side::Left => 1,
+            Side::Right => -1,
+        }
+    }
+}
+
+/// An ID and how often it is on each side.
+#[derive(Debug, Clone)]
+struct Entry {
+    id: u32,
+    left: u64,
+    right: u64,
+}
+
+impl Entry {
+    fn count(&self, side: Side) -> u64 {
+        match side {
+            Side::Left => self.left,
+            Side::Right => self.right,
+        }
+    }
+
+    fn count_mut(&mut self, side: Side) -> &mut u64 {
+        match side {
+            Side::Left => &mut self.left,
+            Side::Right => &mut self.right,
+        }
+    }
+
+    fn other(&self, side: Side) -> u64 {
+        match side {
+            Side::Left => self.right,
+            Side::Right => self.left,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Block {
+    entries: Vec<Entry>,
+    /// Level at the start of the block: left minus right IDs in the blocks before it.
+    offset: i64,
+    /// Left minus right IDs in the block.
+    sum: i64,
+    /// Levels of the block's gaps without the offset, ascending.
+    levels: Vec<i64>,
+    /// Running totals over `levels` of the gap widths and of width times level.
+    widths: Vec<i128>,
+    moments: Vec<i128>,
+}
+
+impl Block {
+    fn new(entries: Vec<Entry>, offset: i64) -> Self {
+        Self {
+            entries,
+            offset,
+            ..Self::default()
+        }
+    }
+
+    fn first(&self) -> u32 {
+        self.entries[0].id
+    }
+
+    /// Recomputes the gap levels; `next` is the first ID of the following block.
+    fn rebuild(&mut self, next: Option<u32>) {
+        let mut gaps: Vec<(i64, i128)> = Vec::with_capacity(self.entries.len());
+        let mut level = 0;
+        for (i, entry) in self.entries.iter().enumerate() {
+            level += entry.left as i64 - entry.right as i64;
+            let end = self.entries.get(i + 1).map(|e| e.id).or(next);
+            if let Some(end) = end {
+                gaps.push((level, (end - entry.id) as i128));
+            }
+        }
+        self.sum = level;
+        gaps.sort_unstable();
+
+        self.levels.clear();
+        self.widths.clear();
+        self.moments.clear();
+        let (mut width, mut moment) = (0, 0);
+        self.widths.push(0);
+        self.moments.push(0);
+        for (level, w) in gaps {
+            width += w;
+            moment += w * level as i128;
+            self.levels.push(level);
+            self.widths.push(width);
+            self.moments.push(moment);
+        }
+    }
+
+    /// The widths of the block's gaps times their distance from level zero.
+    fn cost(&self) -> i128 {
+        let offset = self.offset as i128;
+        let below = self.levels.partition_point(|&level| (level as i128) < -offset);
+        let (width, moment) = (self.widths[self.levels.len()], self.moments[self.levels.len()]);
+        let (width_below, moment_below) = (self.widths[below], self.moments[below]);
+        let above = offset * (width - width_below) + (moment - moment_below);
+        let under = offset * width_below + moment_below;
+        above - under
+    }
+}
+
+/// Two lists of location IDs that answer both parts after every edit.
+#[derive(Debug, Clone)]
+pub struct LocationLists {
+    blocks: Vec<Block>,
+    /// Distinct IDs, and the number of entries the blocks are sized for.
+    ids: usize,
+    block_size: usize,
+    left: u64,
+    right: u64,
+    distance: i128,
+    similarity: u128,
+}
+
+impl Default for LocationLists {
+    fn default() -> Self {
+        Self {
+            blocks: Vec::new(),
+            ids: 0,
+            block_size: MIN_BLOCK,
+            left: 0,
+            right: 0,
+            distance: 0,
+            similarity: 0,
+        }
+    }
+}
+
+impl LocationLists {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Both lists at once, sorted in O(n log n) rather than inserted one by one.
+    pub fn from_lists(left: &[u32], right: &[u32]) -> Self {
+        let mut ids: Vec<(u32, Side)> = left.iter().map(|&id| (id, Side::Left)).collect();
+        ids.extend(right.iter().map(|&id| (id, Side::Right)));
+        ids.sort_unstable_by_key(|&(id, _)| id);
+
+        let mut entries: Vec<Entry> = Vec::new();
+        for (id, side) in ids {
+            if entries.last().is_none_or(|entry| entry.id != id) {
+                entries.push(Entry { id, left: 0, right: 0 });
+            }
+            *entries.last_mut().expect("pushed above").count_mut(side) += 1;
+        }
+
+        let mut lists = Self {
+            left: left.len() as u64,
+            right: right.len() as u64,
+            ..Self::default()
+        };
+        lists.similarity = entries.iter().map(|e| e.id as u128 * e.left as u128 * e.right as u128).sum();
+        lists.regroup(entries);
+        lists
+    }
+
+    /// Puts `entries`, all distinct IDs in order, into blocks sized for their number.
+    fn regroup(&mut self, entries: Vec<Entry>) {
+        self.ids = entries.len();
+        self.block_size = block_size(self.ids);
+        self.blocks.clear();
+        self.distance = 0;
+        let mut chunks = entries.chunks(self.block_size).peekable();
+        while let Some(chunk) = chunks.next() {
+            let offset = self.blocks.last().map_or(0, |block| block.offset + block.sum);
+            let mut block = Block::new(chunk.to_vec(), offset);
+            block.rebuild(chunks.peek().map(|next| next[0].id));
+            self.distance += block.cost();
+            self.blocks.push(block);
+        }
+    }
+
+    pub fn len(&self, side: Side) -> u64 {
+        match side {
+            Side::Left => self.left,
+            Side::Right => self.right,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// How often `id` is in the list on `side`.
+    pub fn count(&self, side: Side, id: u32) -> u64 {
+        self.find(id)
+            .and_then(|(b, i)| i.ok().map(|i| self.blocks[b].entries[i].count(side)))
+            .unwrap_or(0)
+    }
+
+    /// The total distance, while both lists are as long as each other.
+    pub fn distance(&self) -> Option<u64> {
+        (self.left == self.right).then_some(self.distance as u64)
+    }
+
+    pub fn similarity(&self) -> u128 {
+        self.similarity
+    }
+
+    /// The block that holds or would hold `id`, and the entry's index in it or where it goes.
+    fn find(&self, id: u32) -> Option<(usize, Result<usize, usize>)> {
+        if self.blocks.is_empty() {
+            return None;
+        }
+        let b = self.blocks.partition_point(|block| block.first() <= id).saturating_sub(1);
+        Some((b, self.blocks[b].entries.binary_search_by_key(&id, |e| e.id)))
+    }
+
+    fn next_first(&self, b: usize) -> Option<u32> {
+        self.blocks.get(b + 1).map(Block::first)
+    }
+
+    /// Rebuilds block `b` after a change to its entries, keeping the distance current.
+    fn rebuild(&mut self, b: usize) {
+        let next = self.next_first(b);
+        let block = &mut self.blocks[b];
+        self.distance -= block.cost();
+        block.rebuild(next);
+        self.distance += block.cost();
+    }
+
+    /// Moves the level of block `from` and every block after it by `step`.
+    fn shift(&mut self, from: usize, step: i64) {
+        for block in &mut self.blocks[from..] {
+            self.distance -= block.cost();
+            block.offset += step;
+            self.distance += block.cost();
+        }
+    }
+
+    /// Adds `id` to the list on `side`.
+    pub fn insert(&mut self, side: Side, id: u32) {
+        match side {
+            Side::Left => self.left += 1,
+            Side::Right => self.right += 1,
+        }
+        let Some((b, found)) = self.find(id) else {
+            let mut block = Block::new(vec![Entry { id, left: 0, right: 0 }], 0);
+            *block.entries[0].count_mut(side) += 1;
+            block.rebuild(None);
+            self.blocks.push(block);
+            self.ids = 1;
+            return;
+        };
+
+        let i = found.unwrap_or_else(|i| {
+            self.blocks[b].entries.insert(i, Entry { id, left: 0, right: 0 });
+            self.ids += 1;
+            i
+        });
+        let entry = &mut self.blocks[b].entries[i];
+        self.similarity += id as u128 * entry.other(side) as u128;
+        *entry.count_mut(side) += 1;
+        // A new first ID only ever goes into the first block, so the gaps before this block stay.
+        self.rebuild(b);
+        self.shift(b + 1, side.step());
+        self.balance(b);
+    }
+
+    /// Removes one `id` from the list on `side`; false if it is not there.
+    pub fn remove(&mut self, side: Side, id: u32) -> bool {
+        let Some((b, Ok(i))) = self.find(id) else {
+            return false;
+        };
+        let entry = &mut self.blocks[b].entries[i];
+        if entry.count(side) == 0 {
+            return false;
+        }
+        match side {
+            Side::Left => self.left -= 1,
+            Side::Right => self.right -= 1,
+        }
+        *entry.count_mut(side) -= 1;
+        self.similarity -= id as u128 * entry.other(side) as u128;
+
+        if entry.left + entry.right > 0 {
+            self.rebuild(b);
+            self.shift(b + 1, -side.step());
+            return true;
+        }
+
+        self.ids -= 1;
+        self.blocks[b].entries.remove(i);
+        if self.blocks[b].entries.is_empty() {
+            self.distance -= self.blocks[b].cost();
+            self.blocks.remove(b);
+            // The blocks after it moved down into its place.
+            self.shift(b, -side.step());
+            if b > 0 {
+                self.rebuild(b - 1);
+            }
+        } else {
+            self.rebuild(b);
+            if i == 0 && b > 0 {
+                // The previous block's last gap now ends at a later ID.
+                self.rebuild(b - 1);
+            }
+            self.shift(b + 1, -side.step());
+        }
+        self.balance(b.min(self.blocks.len().saturating_sub(1)));
+        true
+    }
+
+    /// Keeps block `b` within a factor of two of the block size, and regroups all blocks once the
+    /// block size is off by more than that.
+    fn balance(&mut self, b: usize) {
+        let target = block_size(self.ids);
+        if target > 2 * self.block_size || 2 * target < self.block_size {
+            let entries = self.blocks.drain(..).flat_map(|block| block.entries).collect();
+            self.regroup(entries);
+            return;
+        }
+        let Some(block) = self.blocks.get(b) else {
+            return;
+        };
+        if block.entries.len() > 2 * self.block_size {
+            self.split(b);
+        } else if 2 * block.entries.len() < self.block_size && self.blocks.len() > 1 {
+            let b = b.min(self.blocks.len() - 2);
+            self.merge(b);
+            if self.blocks[b].entries.len() > 2 * self.block_size {
+                self.split(b);
+            }
+        }
+    }
+
+    /// Merges block `b + 1` into block `b`.
+    fn merge(&mut self, b: usize) {
+        let next = self.blocks.remove(b + 1);
+        self.distance -= next.cost();
+        self.blocks[b].entries.extend(next.entries);
+        self.rebuild(b);
+    }
+
+    /// Splits an oversized block `b` in halves.
+    fn split(&mut self, b: usize) {
+        let block = &mut self.blocks[b];
+        let tail = block.entries.split_off(block.entries.len() / 2);
+        self.distance -= block.cost();
+        block.rebuild(Some(tail[0].id));
+        self.distance += block.cost();
+        let offset = block.offset + block.sum;
+        let mut second = Block::new(tail, offset);
+        second.rebuild(self.next_first(b));
+        self.distance += second.cost();
+        self.blocks.insert(b + 1, second);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    /// Both answers from scratch; no distance for lists of different lengths.
+    fn brute_force(left: &[u32], right: &[u32]) -> (Option<u64>, u128) {
+        let (mut left, mut right) = (left.to_vec(), right.to_vec());
+        left.sort_unstable();
+        right.sort_unstable();
+        let distance = (left.len() == right.len())
+            .then(|| left.iter().zip(&right).map(|(l, r)| l.abs_diff(*r) as u64).sum());
+        let similarity = left
+            .iter()
+            .map(|&l| l as u128 * (right.partition_point(|&r| r <= l) - right.partition_point(|&r| r < l)) as u128)
+            .sum();
+        (distance, similarity)
+    }
+
+    #[test]
+    fn test_example() {
+        let lists = LocationLists::from_lists(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]);
+        assert_eq!((lists.distance(), lists.similarity()), (Some(11), 31));
+
+        let mut lists = LocationLists::new();
+        for (l, r) in [(3, 4), (4, 3), (2, 5), (1, 3), (3, 9), (3, 3)] {
+            lists.insert(Side::Left, l);
+            assert_eq!(lists.distance(), None);
+            lists.insert(Side::Right, r);
+        }
+        assert_eq!((lists.distance(), lists.similarity()), (Some(11), 31));
+        assert_eq!(lists.count(Side::Left, 3), 3);
+
+        assert!(lists.remove(Side::Left, 1) && lists.remove(Side::Right, 9));
+        assert_eq!(brute_force(&[3, 4, 2, 3, 3], &[4, 3, 5, 3, 3]), (lists.distance(), lists.similarity()));
+        assert!(!lists.remove(Side::Right, 9));
+        assert!(!lists.remove(Side::Right, 2));
+    }
+
+    /// Checks that the blocks stay near the square root of the number of distinct IDs.
+    fn assert_balanced(lists: &LocationLists) {
+        let ids: usize = lists.blocks.iter().map(|block| block.entries.len()).sum();
+        assert_eq!(ids, lists.ids);
+        let target = block_size(ids);
+        let size = lists.block_size;
+        assert!(target <= 2 * size && size <= 2 * target, "blocks of {} for {} IDs", size, ids);
+        assert!(lists.blocks.iter().all(|block| block.entries.len() <= 2 * lists.block_size));
+        assert!(lists.blocks.len() <= 2 * ids / lists.block_size + 2, "{} blocks", lists.blocks.len());
+    }
+
+    #[test]
+    fn test_many_blocks() {
+        // Enough distinct IDs to grow the blocks twice, then removals that shrink them again.
+        let mut rng = fastrand::Rng::with_seed(1);
+        let mut lists = LocationLists::new();
+        let (mut left, mut right) = (Vec::new(), Vec::new());
+        for step in 0..16000 {
+            let side = if rng.bool() { Side::Left } else { Side::Right };
+            let ids = if side == Side::Left { &mut left } else { &mut right };
+            if step > 8000 && !ids.is_empty() && rng.u8(..8) > 0 {
+                let id = ids.swap_remove(rng.usize(..ids.len()));
+                assert!(lists.remove(side, id));
+            } else {
+                let id = rng.u32(..50000);
+                ids.push(id);
+                lists.insert(side, id);
+            }
+            if step % 397 == 0 {
+                assert_eq!((lists.distance(), lists.similarity()), brute_force(&left, &right), "step {}", step);
+                assert_balanced(&lists);
+            }
+            if step == 8000 {
+                assert!(lists.block_size > 4 * MIN_BLOCK && lists.blocks.len() > 40);
+            }
+        }
+        assert!(left.len() + right.len() < 2000);
+        assert_balanced(&lists);
+        let rebuilt = LocationLists::from_lists(&left, &right);
+        assert_eq!((lists.distance, lists.similarity), (rebuilt.distance, rebuilt.similarity));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_brute_force(ops in vec((any::<bool>(), any::<bool>(), 0u32..40), 1..200)) {
+            let mut lists = LocationLists::new();
+            let (mut left, mut right) = (Vec::new(), Vec::new());
+            for (is_left, remove, id) in ops {
+                let (side, ids) = if is_left { (Side::Left, &mut left) } else { (Side::Right, &mut right) };
+                if remove {
+                    let position = ids.iter().position(|&x| x == id);
+                    prop_assert_eq!(lists.remove(side, id), position.is_some());
+                    if let Some(position) = position {
+                        ids.swap_remove(position);
+                    }
+                } else {
+                    lists.insert(side, id);
+                    ids.push(id);
+                }
+                prop_assert_eq!((lists.distance(), lists.similarity()), brute_force(&left, &right));
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+use tracing::debug;
+
+pub mod columns;
+pub mod generator;
+pub mod incremental;
+pub mod stream;
+
+/*
+https://adventofcode.com/2024/day/1
+*/
+
+fn parse_lists(contents: &str) -> ParseResult<(Vec<u32>, Vec<u32>)> {
+    let input = Input::new(1, contents);
+    let mut left_side: Vec<u32> = Vec::new();
+    let mut right_side: Vec<u32> = Vec::new();
+    for line in contents.lines().filter(|line| !line.is_empty()) {
+        let mut split_line = line.split_whitespace();
+        let (Some(lhs), Some(rhs), None) = (split_line.next(), split_line.next(), split_line.next())
+        else {
+            return Err(input.error(line, "expected two location IDs"));
+        };
+        left_side.push(input.parse(lhs)?);
+        right_side.push(input.parse(rhs)?);
+    }
+    left_side.sort();
+    right_side.sort();
+    Ok((left_side, right_side))
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_lists(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    debug!("{}", contents);
+    let (left_side, right_side) = timed_parse(|| parse_lists(contents))?;
+
+    let difference: u64 = left_side
+        .iter()
+        .zip(right_side)
+        .fold(0, |acc, (&lhs, rhs)| acc + u64::from(lhs.abs_diff(rhs)));
+    Ok(difference.into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let (left_side, right_side) = timed_parse(|| parse_lists(contents))?;
+    let mut similarity_score = 0;
+    assert_eq!(left_side.len(), right_side.len());
+    for idx in left_side {
+        similarity_score += u64::from(idx) * right_side.iter().filter(|&x| *x == idx).count() as u64;
+    }
+
+    Ok(similarity_score.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(11));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(31));
+    }
+
+    #[test]
+    fn test_large_ids() {
+        let lists = "4294967295   0\n4294967295   0\n4294967295   4294967295\n";
+        assert_eq!(part1(lists).unwrap(), Answer::Number(2 * 4294967295));
+        assert_eq!(part2(lists).unwrap(), Answer::Number(3 * 4294967295));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 50);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
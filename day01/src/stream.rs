@@ -0,0 +1,334 @@
+use anyhow::{bail, Context, Result};
+use common::{AocError, Input, ParseResult};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Seek, Write};
+use std::path::PathBuf;
+use tracing::debug;
+
+/*
+Location lists of any size, read from a stream.
+
+Each line holds a left and a right location ID, separated by whitespace, commas or semicolons, so
+the puzzle's format and CSV exports both work; a first line without digits is taken for a CSV
+header. Both columns go into one sort, each ID tagged with its side. When the IDs outgrow the memory
+budget, sorted runs are spilled to temporary files and merged, so the lists only need disk space.
+
+One pass over the merged IDs then gives both answers. The similarity score adds `id * left * right`
+for the number of times an ID appears on each side. The total distance pairs the i-th smallest IDs
+of both sides, which adds up to the area between the two counting functions: between consecutive
+IDs `a < b`, `|left IDs <= a - right IDs <= a|` pairs straddle the gap and each adds `b - a`.
+*/
+
+/// Most runs merged at once; more are merged in rounds, to bound open files.
+const MAX_FAN_IN: usize = 64;
+
+const LEFT: u64 = 0;
+const RIGHT: u64 = 1;
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Bytes of IDs to sort in memory before spilling a run to disk.
+    pub memory_budget: usize,
+    /// Where to put the runs; the system's temporary directory by default.
+    pub spill_dir: Option<PathBuf>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            memory_budget: 256 << 20,
+            spill_dir: None,
+        }
+    }
+}
+
+/// Both answers for a pair of location lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Totals {
+    pub pairs: u64,
+    pub distance: u64,
+    /// Wider than the answers, since it grows with the square of the list length.
+    pub similarity: u128,
+}
+
+/// Parses one line into its two IDs, None for a blank line.
+fn parse_line(line: &str, number: usize) -> ParseResult<Option<(u32, u32)>> {
+    let input = Input::new(1, line);
+    let mut fields = line
+        .split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+        .filter(|field| !field.is_empty())
+        .map(|field| field.trim_matches('"'));
+    let result = match (fields.next(), fields.next(), fields.next()) {
+        (None, ..) => return Ok(None),
+        (Some(lhs), Some(rhs), None) => input.parse(lhs).and_then(|lhs| Ok(Some((lhs, input.parse(rhs)?)))),
+        _ => Err(input.error(line, "expected two location IDs")),
+    };
+    // The error points into the line alone; put it back in its place in the input.
+    result.map_err(|err| match err {
+        AocError::Parse {
+            day,
+            column,
+            width,
+            message,
+            source_line,
+            ..
+        } => AocError::Parse {
+            day,
+            line: number,
+            column,
+            width,
+            message,
+            source_line,
+        },
+        err => err,
+    })
+}
+
+/// Sorts more values than fit in memory, through runs on disk.
+struct ExternalSorter {
+    /// Holds the budget's worth of values and never grows past it.
+    buffer: Vec<u64>,
+    runs: Vec<File>,
+    spill_dir: Option<PathBuf>,
+}
+
+impl ExternalSorter {
+    fn new(options: &Options) -> Result<Self> {
+        let capacity = (options.memory_budget / size_of::<u64>()).max(1);
+        let mut buffer = Vec::new();
+        buffer
+            .try_reserve_exact(capacity)
+            .with_context(|| format!("Failed to reserve {} bytes of memory", options.memory_budget))?;
+        Ok(Self {
+            buffer,
+            runs: Vec::new(),
+            spill_dir: options.spill_dir.clone(),
+        })
+    }
+
+    fn push(&mut self, value: u64) -> Result<()> {
+        if self.buffer.len() == self.buffer.capacity() {
+            self.spill()?;
+        }
+        self.buffer.push(value);
+        Ok(())
+    }
+
+    fn temp_file(&self) -> Result<File> {
+        match &self.spill_dir {
+            Some(dir) => tempfile::tempfile_in(dir),
+            None => tempfile::tempfile(),
+        }
+        .context("Failed to create a file for a sorted run")
+    }
+
+    fn spill(&mut self) -> Result<()> {
+        self.buffer.sort_unstable();
+        let mut file = self.temp_file()?;
+        write_run(&mut file, self.buffer.drain(..).map(Ok))?;
+        self.runs.push(file);
+        debug!(runs = self.runs.len(), "spilled a sorted run");
+        Ok(())
+    }
+
+    /// All values pushed, in ascending order.
+    fn finish(mut self) -> Result<Box<dyn Iterator<Item = io::Result<u64>>>> {
+        if self.runs.is_empty() {
+            self.buffer.sort_unstable();
+            return Ok(Box::new(self.buffer.into_iter().map(Ok)));
+        }
+        if !self.buffer.is_empty() {
+            self.spill()?;
+        }
+        let mut runs = std::mem::take(&mut self.runs);
+        while runs.len() > MAX_FAN_IN {
+            debug!(runs = runs.len(), "merging runs in a round");
+            let mut merged = Vec::new();
+            let mut pending = runs.into_iter().peekable();
+            while pending.peek().is_some() {
+                let mut file = self.temp_file()?;
+                write_run(&mut file, Merge::new(pending.by_ref().take(MAX_FAN_IN))?)?;
+                merged.push(file);
+            }
+            runs = merged;
+        }
+        Ok(Box::new(Merge::new(runs)?))
+    }
+}
+
+/// Writes `values` to `file` and rewinds it for reading.
+fn write_run(file: &mut File, values: impl Iterator<Item = io::Result<u64>>) -> Result<()> {
+    let mut writer = BufWriter::new(&mut *file);
+    for value in values {
+        writer.write_all(&value?.to_le_bytes())?;
+    }
+    writer.flush()?;
+    drop(writer);
+    file.rewind()?;
+    Ok(())
+}
+
+/// The values of sorted runs, merged into one ascending sequence.
+struct Merge {
+    readers: Vec<BufReader<File>>,
+    heap: BinaryHeap<Reverse<(u64, usize)>>,
+}
+
+impl Merge {
+    fn new(runs: impl IntoIterator<Item = File>) -> io::Result<Self> {
+        let mut merge = Self {
+            readers: runs.into_iter().map(BufReader::new).collect(),
+            heap: BinaryHeap::new(),
+        };
+        for run in 0..merge.readers.len() {
+            merge.advance(run)?;
+        }
+        Ok(merge)
+    }
+
+    /// Moves the next value of `run` onto the heap.
+    fn advance(&mut self, run: usize) -> io::Result<()> {
+        let mut bytes = [0; size_of::<u64>()];
+        match self.readers[run].read_exact(&mut bytes) {
+            Ok(()) => self.heap.push(Reverse((u64::from_le_bytes(bytes), run))),
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => {}
+            Err(err) => return Err(err),
+        }
+        Ok(())
+    }
+}
+
+impl Iterator for Merge {
+    type Item = io::Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((value, run)) = self.heap.pop()?;
+        Some(self.advance(run).map(|()| value))
+    }
+}
+
+/// Reads location lists from `reader` and computes both answers.
+pub fn totals(reader: impl BufRead, options: &Options) -> Result<Totals> {
+    let mut sorter = ExternalSorter::new(options)?;
+    let mut pairs = 0u64;
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.context("Failed to read the location lists")?;
+        let pair = match parse_line(&line, index + 1) {
+            Ok(pair) => pair,
+            Err(_) if index == 0 && !line.contains(|c: char| c.is_ascii_digit()) => None,
+            Err(err) => return Err(err.into()),
+        };
+        if let Some((lhs, rhs)) = pair {
+            sorter.push((lhs as u64) << 1 | LEFT)?;
+            sorter.push((rhs as u64) << 1 | RIGHT)?;
+            pairs += 1;
+        }
+    }
+
+    let mut totals = Totals {
+        pairs,
+        distance: 0,
+        similarity: 0,
+    };
+    // Left IDs seen minus right IDs seen, and how often the current ID appeared on each side.
+    let mut unpaired: i64 = 0;
+    let mut current: Option<(u64, u64, u64)> = None;
+    for key in sorter.finish()? {
+        let key = key.context("Failed to read back a sorted run")?;
+        let id = key >> 1;
+        match current {
+            Some((previous, left, right)) if previous != id => {
+                totals.similarity += previous as u128 * left as u128 * right as u128;
+                totals.distance += unpaired.unsigned_abs() * (id - previous);
+                current = Some((id, 0, 0));
+            }
+            None => current = Some((id, 0, 0)),
+            _ => {}
+        }
+        let (_, left, right) = current.as_mut().expect("set above");
+        if key & 1 == LEFT {
+            unpaired += 1;
+            *left += 1;
+        } else {
+            unpaired -= 1;
+            *right += 1;
+        }
+    }
+    if let Some((id, left, right)) = current {
+        totals.similarity += id as u128 * left as u128 * right as u128;
+    }
+    if unpaired != 0 {
+        bail!("The merged lists lost IDs");
+    }
+    Ok(totals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generator, part1, part2};
+    use common::generator::GenOptions;
+    use common::Answer;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        let totals = totals(EXAMPLE.as_bytes(), &Options::default()).unwrap();
+        assert_eq!(
+            totals,
+            Totals {
+                pairs: 6,
+                distance: 11,
+                similarity: 31
+            }
+        );
+    }
+
+    #[test]
+    fn test_delimiters() {
+        let csv = "left,right\r\n3,4\r\n4;3\r\n\"2\", \"5\"\r\n1\t3\r\n\r\n3 9\r\n3,3\r\n";
+        let totals = totals(csv.as_bytes(), &Options::default()).unwrap();
+        assert_eq!((totals.pairs, totals.distance, totals.similarity), (6, 11, 31));
+    }
+
+    #[test]
+    fn test_reports_line_of_error() {
+        let err = totals("3   4\n4   3\n2   x\n".as_bytes(), &Options::default()).unwrap_err();
+        assert!(err.to_string().starts_with("day 01, line 3, column 5: expected u32, found `x`"), "{}", err);
+        let err = totals("3   4   5\n".as_bytes(), &Options::default()).unwrap_err();
+        assert!(err.to_string().contains("expected two location IDs"));
+    }
+
+    #[test]
+    fn test_rejects_budget_beyond_memory() {
+        let options = Options {
+            memory_budget: usize::MAX,
+            spill_dir: None,
+        };
+        let err = totals("3   4\n".as_bytes(), &options).unwrap_err();
+        assert!(err.to_string().starts_with("Failed to reserve 18446744073709551615 bytes"), "{}", err);
+    }
+
+    #[test]
+    fn test_spills_match_in_memory() {
+        let dir = tempfile::tempdir().unwrap();
+        for seed in 0..3 {
+            let generated = generator::generate(&GenOptions::new(3000, seed));
+            let in_memory = totals(generated.input.as_bytes(), &Options::default()).unwrap();
+            // 16 IDs per run: 375 runs, which takes two rounds of merging.
+            let options = Options {
+                memory_budget: 128,
+                spill_dir: Some(dir.path().to_path_buf()),
+            };
+            let spilled = totals(generated.input.as_bytes(), &options).unwrap();
+            assert_eq!(spilled, in_memory);
+            assert_eq!(part1(&generated.input).unwrap(), Answer::Number(spilled.distance as u64));
+            assert_eq!(part2(&generated.input).unwrap(), Answer::Number(spilled.similarity as u64));
+        }
+        // Runs are anonymous files, gone once merged.
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+}
@@ -0,0 +1,44 @@
+use clap::Parser;
+use day02::dampener::min_removals;
+use day02::policy::find_policy;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+/// Finds the fewest levels to remove from each report to make it safe
+#[derive(Parser)]
+struct Args {
+    /// Reports, or `-` for standard input [default: $AOC_INPUT, then the day's input]
+    input: Option<OsString>,
+    /// Most levels the dampener may remove from a report
+    #[arg(long, default_value_t = 1)]
+    tolerance: usize,
+    /// TOML file of named policies
+    #[arg(long)]
+    config: Option<PathBuf>,
+    /// Policy that makes a report safe; `puzzle` is built in
+    #[arg(long, default_value = "puzzle")]
+    policy: String,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let policy = find_policy(args.config.as_deref(), &args.policy)?;
+    let contents = common::resolve_input(2, args.input).read()?;
+    let reports = day02::parse_reports(&contents)?;
+    let mut safe = 0;
+    for levels in &reports {
+        let line: Vec<String> = levels.iter().map(u32::to_string).collect();
+        let removals = min_removals(&policy, levels);
+        match removals.len() {
+            0 => println!("SAFE    {}", line.join(" ")),
+            n if n <= args.tolerance => {
+                let positions: Vec<String> = removals.iter().map(|i| (i + 1).to_string()).collect();
+                println!("FIXED   {}  (remove levels {})", line.join(" "), positions.join(", "));
+            }
+            n => println!("UNSAFE  {}  (needs {} removals)", line.join(" "), n),
+        }
+        safe += (removals.len() <= args.tolerance) as usize;
+    }
+    println!("Safe reports: {} of {}", safe, reports.len());
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anyhow::Result;
+use clap::Parser;
+use day02::policy::find_policy;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+/// Classifies each report against a safety policy
+#[derive(Parser)]
+struct Args {
+    /// Reports, or `-` for standard input [default: $AOC_INPUT, then the day's input]
+    input: Option<OsString>,
+    /// TOML file of named policies
+    #[arg(long)]
+    config: Option<PathBuf>,
+    /// Policy to apply; `puzzle` is built in
+    #[arg(long, default_value = "puzzle")]
+    policy: String,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let policy = find_policy(args.config.as_deref(), &args.policy)?;
+
+    let contents = common::resolve_input(2, args.input).read()?;
+    let reports = day02::parse_reports(&contents)?;
+    let mut safe = 0;
+    for levels in &reports {
+        let line: Vec<String> = levels.iter().map(u32::to_string).collect();
+        match policy.classify(levels) {
+            Ok(()) => {
+                safe += 1;
+                println!("SAFE    {}", line.join(" "));
+            }
+            Err(violation) => println!("UNSAFE  {}  ({})", line.join(" "), violation),
+        }
+    }
+    println!("Safe reports: {} of {}", safe, reports.len());
+    Ok(())
+}
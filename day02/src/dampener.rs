@@ -0,0 +1,274 @@
+use crate::policy::{Bounds, Constraint, Direction, Policy};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+/*
+The Problem Dampener, for any number of bad levels and any policy.
+
+Removing levels leaves a subsequence of the report, so the fewest removals that make a report safe
+leave its longest safe subsequence. For one direction, the longest safe subsequence ending at a
+level extends the longest one ending at a level a step below it (or above, going down). Keeping the
+best chain for each level value seen so far makes that one lookup per allowed step, so for the
+puzzle's steps of 1 to 3 it is three lookups per level, linear time for both directions together.
+When the steps may be wider than the number of values seen so far, going through those values
+instead is quadratic at worst.
+
+Levels outside the policy's bounds can't be kept at all. With a limit on plateaus, a value keeps a
+best chain for each length of the plateau it ends in, and only a chain whose plateau is still
+short enough can take another equal level.
+*/
+
+/// Which way the levels may go from one kept level to the next; equal levels go either way.
+#[derive(Clone, Copy)]
+struct Mode {
+    up: bool,
+    down: bool,
+}
+
+/// The constraints of a policy, merged by kind.
+struct Rules {
+    levels: Bounds,
+    steps: Bounds,
+    /// A safe report follows one of these.
+    modes: Vec<Mode>,
+    max_plateau: Option<usize>,
+}
+
+impl Rules {
+    fn new(policy: &Policy) -> Self {
+        let mut rules = Rules {
+            levels: Bounds::ALL,
+            steps: Bounds::ALL,
+            modes: Vec::new(),
+            max_plateau: None,
+        };
+        let narrow = |bounds: &mut Bounds, other: Bounds| {
+            bounds.min = bounds.min.max(other.min);
+            bounds.max = bounds.max.min(other.max);
+        };
+        let (mut directed, mut rising, mut falling) = (false, true, true);
+        for &constraint in &policy.constraints {
+            match constraint {
+                Constraint::Levels(bounds) => narrow(&mut rules.levels, bounds),
+                Constraint::Steps(bounds) => narrow(&mut rules.steps, bounds),
+                Constraint::Direction(direction) => {
+                    directed = true;
+                    rising &= direction != Direction::Decreasing;
+                    falling &= direction != Direction::Increasing;
+                }
+                Constraint::MaxPlateau(max) => {
+                    rules.max_plateau = Some(rules.max_plateau.map_or(max, |limit| limit.min(max)));
+                }
+            }
+        }
+        let (up, down) = (Mode { up: true, down: false }, Mode { up: false, down: true });
+        rules.modes = match (directed, rising, falling) {
+            (false, _, _) => vec![Mode { up: true, down: true }],
+            (true, true, true) => vec![up, down],
+            (true, true, false) => vec![up],
+            (true, false, true) => vec![down],
+            // Both increasing and decreasing leaves only equal levels.
+            (true, false, false) => vec![Mode { up: false, down: false }],
+        };
+        rules
+    }
+
+    /// Whether `level` may follow a different `before` in `mode`.
+    fn allows(&self, mode: Mode, before: u32, level: u32) -> bool {
+        let direction = if level > before { mode.up } else { mode.down };
+        direction && self.steps.contains(before.abs_diff(level))
+    }
+
+    /// The values other than `level` that it may follow in `mode`, below and above it.
+    fn window(&self, mode: Mode, level: u32) -> [Option<RangeInclusive<u32>>; 2] {
+        let nearest = self.steps.min.max(1);
+        let below = level.checked_sub(nearest).filter(|_| mode.up);
+        let above = level.checked_add(nearest).filter(|_| mode.down);
+        [
+            below.map(|high| level.saturating_sub(self.steps.max)..=high),
+            above.map(|low| low..=level.saturating_add(self.steps.max)),
+        ]
+    }
+
+    /// Indices of the longest subsequence of `levels` that is safe and goes the way of `mode`.
+    fn longest_chain(&self, levels: &[u32], mode: Mode) -> Vec<usize> {
+        let runs = self.max_plateau.unwrap_or(1);
+        // The kept levels of every chain, as their index and the state of the chain before it.
+        let mut states: Vec<(usize, Option<usize>)> = Vec::new();
+        // The longest chain ending with each value so far, for each length of its final plateau, as
+        // its length and state.
+        let mut best: HashMap<u32, Vec<Option<(usize, usize)>>> = HashMap::new();
+        let mut longest: Option<(usize, usize)> = None;
+        for (i, &level) in levels.iter().enumerate() {
+            if !self.levels.contains(level) {
+                continue;
+            }
+            let longest_of = |chains: &Vec<Option<(usize, usize)>>| chains.iter().flatten().max().copied();
+            // Chains that this level extends with a step, starting a plateau of 1.
+            let window = self.window(mode, level);
+            let widths = window.iter().flatten().filter(|values| !values.is_empty());
+            let width: u64 = widths.map(|values| u64::from(values.end() - values.start()) + 1).sum();
+            let mut before = if width <= best.len() as u64 {
+                let values = window.into_iter().flatten().flatten();
+                values.filter_map(|value| best.get(&value).and_then(longest_of)).max()
+            } else {
+                let earlier = best.iter().filter(|&(&value, _)| value != level);
+                let earlier = earlier.filter(|&(&value, _)| self.allows(mode, value, level));
+                earlier.filter_map(|(_, chains)| longest_of(chains)).max()
+            };
+            // Chains ending in this value that this level lengthens the plateau of, by the new length.
+            let mut extended = vec![None; runs];
+            if let Some(chains) = best.get(&level).filter(|_| self.steps.contains(0)) {
+                match self.max_plateau {
+                    Some(_) => (1..runs).for_each(|run| extended[run] = chains[run - 1].map(Some)),
+                    None => before = before.max(chains[0]),
+                }
+            }
+            extended[0] = Some(before);
+
+            let chains = best.entry(level).or_insert_with(|| vec![None; runs]);
+            for (run, previous) in extended.into_iter().enumerate() {
+                let Some(previous) = previous else {
+                    continue;
+                };
+                let chain = (previous.map_or(0, |(length, _)| length) + 1, states.len());
+                states.push((i, previous.map(|(_, state)| state)));
+                chains[run] = chains[run].max(Some(chain));
+                longest = longest.max(Some(chain));
+            }
+        }
+
+        let mut chain = Vec::new();
+        let mut next = longest.map(|(_, state)| state);
+        while let Some(state) = next {
+            chain.push(states[state].0);
+            next = states[state].1;
+        }
+        chain.reverse();
+        chain
+    }
+}
+
+/// The fewest indices of levels to remove for the report to be safe under `policy`: the levels
+/// outside its longest safe subsequence.
+pub fn min_removals(policy: &Policy, levels: &[u32]) -> Vec<usize> {
+    let rules = Rules::new(policy);
+    let chains = rules.modes.iter().map(|&mode| rules.longest_chain(levels, mode));
+    // The first of the longest, so rising wins a tie.
+    let kept = chains.reduce(|kept, chain| if chain.len() > kept.len() { chain } else { kept });
+    let mut kept = kept.unwrap_or_default().into_iter().peekable();
+    (0..levels.len()).filter(|&i| kept.next_if_eq(&i).is_none()).collect()
+}
+
+/// The levels to remove to make the report safe, when there are at most `tolerance` of them.
+pub fn dampen(policy: &Policy, levels: &[u32], tolerance: usize) -> Option<Vec<usize>> {
+    Some(min_removals(policy, levels)).filter(|removals| removals.len() <= tolerance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::parse_policies;
+    use proptest::collection::vec;
+    use proptest::option;
+    use proptest::prelude::*;
+    use std::ops::Range;
+
+    const POLICIES: &str = include_str!("../policies.toml");
+
+    /// The levels that are left after removing `removals`.
+    fn without(levels: &[u32], removals: &[usize]) -> Vec<u32> {
+        let kept = levels.iter().enumerate().filter(|(i, _)| !removals.contains(i));
+        kept.map(|(_, &level)| level).collect()
+    }
+
+    /// The fewest removals that make `levels` safe under `policy`, by trying every subset of levels.
+    fn brute_force(policy: &Policy, levels: &[u32]) -> usize {
+        (0u32..1 << levels.len())
+            .filter(|mask| {
+                let removals: Vec<usize> = (0..levels.len()).filter(|i| mask & 1 << i != 0).collect();
+                policy.is_safe(&without(levels, &removals))
+            })
+            .map(|mask| mask.count_ones() as usize)
+            .min()
+            .expect("an empty report is safe")
+    }
+
+    #[test]
+    fn test_example() {
+        let puzzle = Policy::puzzle();
+        let reports: [&[u32]; 6] = [
+            &[7, 6, 4, 2, 1],
+            &[1, 2, 7, 8, 9],
+            &[9, 7, 6, 2, 1],
+            &[1, 3, 2, 4, 5],
+            &[8, 6, 4, 4, 1],
+            &[1, 3, 6, 7, 9],
+        ];
+        let dampened: Vec<_> = reports.iter().map(|levels| dampen(&puzzle, levels, 1)).collect();
+        assert_eq!(dampened, [Some(vec![]), None, None, Some(vec![1]), Some(vec![2]), Some(vec![])]);
+        assert_eq!(dampen(&puzzle, &[1, 2, 7, 8, 9], 2), Some(vec![0, 1]));
+        assert_eq!(dampen(&puzzle, &[], 0), Some(vec![]));
+    }
+
+    #[test]
+    fn test_removes_many_levels() {
+        let puzzle = Policy::puzzle();
+        // Every other level is a spike.
+        let levels = [10, 50, 12, 1, 14, 90, 16, 0, 18];
+        assert_eq!(min_removals(&puzzle, &levels), [1, 3, 5, 7]);
+        assert_eq!(dampen(&puzzle, &levels, 3), None);
+        // At the ends of the range of levels.
+        assert_eq!(min_removals(&puzzle, &[u32::MAX, 0, u32::MAX - 2, 1]), [0, 2]);
+    }
+
+    #[test]
+    fn test_policy_file() {
+        let policies = parse_policies(POLICIES).unwrap();
+        // Out of range, then a plateau of three.
+        assert_eq!(min_removals(&policies["gentle"], &[0, 1, 1, 1, 3, 120]), [0, 1, 5]);
+        // Falls fast, but never stalls.
+        assert_eq!(min_removals(&policies["draining"], &[90, 90, 40, 60, 3, 3, 0]), [0, 2, 4]);
+    }
+
+    fn policies() -> impl Strategy<Value = Policy> {
+        let bounds = |values: Range<u32>| {
+            let ends = (values.clone(), values);
+            option::of(ends.prop_map(|(a, b)| Bounds { min: a.min(b), max: a.max(b) }))
+        };
+        let direction = prop_oneof![
+            Just(Direction::Increasing),
+            Just(Direction::Decreasing),
+            Just(Direction::Either),
+        ];
+        let constraints = (bounds(2..10), bounds(0..4), option::of(direction), option::of(1usize..4));
+        constraints.prop_map(|(levels, steps, direction, plateau)| {
+            let constraints = [
+                levels.map(Constraint::Levels),
+                steps.map(Constraint::Steps),
+                direction.map(Constraint::Direction),
+                plateau.map(Constraint::MaxPlateau),
+            ];
+            Policy {
+                constraints: constraints.into_iter().flatten().collect(),
+            }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_removes_fewest_levels(levels in vec(0u32..12, 0..12)) {
+            let puzzle = Policy::puzzle();
+            let removals = min_removals(&puzzle, &levels);
+            prop_assert!(puzzle.is_safe(&without(&levels, &removals)));
+            prop_assert_eq!(removals.len(), brute_force(&puzzle, &levels));
+        }
+
+        #[test]
+        fn prop_removes_fewest_levels_for_any_policy(policy in policies(), levels in vec(0u32..12, 0..12)) {
+            let removals = min_removals(&policy, &levels);
+            prop_assert!(policy.is_safe(&without(&levels, &removals)));
+            prop_assert_eq!(removals.len(), brute_force(&policy, &levels));
+        }
+    }
+}
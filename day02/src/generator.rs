@@ -0,0 +1,60 @@
+use common::generator::{GenOptions, Generated, Rng};
+
+/*
+`size` reports (1000 by default) built from a safe run of levels. A quarter get one defect, a
+level repeated or a spike far off its neighbours, which the dampener removes. Another quarter get
+two defects at least one level apart, which no single removal fixes.
+*/
+
+fn safe_levels(rng: &mut Rng) -> Vec<u32> {
+    let len = rng.usize(5..=8);
+    let sign = if rng.bool() { 1 } else { -1 };
+    let mut level = rng.i32(30..=70);
+    let mut levels = vec![level as u32];
+    for _ in 1..len {
+        level += sign * rng.i32(1..=3);
+        levels.push(level as u32);
+    }
+    levels
+}
+
+/// Inserts a defect before index `at`: a copy of a neighbour, or a spike.
+fn add_defect(rng: &mut Rng, levels: &mut Vec<u32>, at: usize) {
+    let neighbour = levels[at.min(levels.len() - 1)];
+    let defect = match rng.bool() {
+        true => neighbour,
+        false if neighbour < 50 => neighbour + rng.u32(20..=25),
+        false => neighbour - rng.u32(20..=25),
+    };
+    levels.insert(at, defect);
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let reports = options.size_or(1000);
+    let (mut safe, mut dampened) = (0u64, 0u64);
+
+    let mut input = String::new();
+    for _ in 0..reports {
+        let mut levels = safe_levels(&mut rng);
+        match rng.u8(..4) {
+            0 | 1 => safe += 1,
+            2 => {
+                let at = rng.usize(..=levels.len());
+                add_defect(&mut rng, &mut levels, at);
+                dampened += 1;
+            }
+            _ => {
+                let first = rng.usize(..levels.len() - 2);
+                let second = rng.usize(first + 2..=levels.len());
+                add_defect(&mut rng, &mut levels, second);
+                add_defect(&mut rng, &mut levels, first);
+            }
+        }
+        let line: Vec<String> = levels.iter().map(u32::to_string).collect();
+        input += &line.join(" ");
+        input.push('\n');
+    }
+
+    Generated::unknown(input).part1(safe).part2(safe + dampened)
+}
@@ -0,0 +1,84 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+
+pub mod dampener;
+pub mod generator;
+pub mod policy;
+
+use policy::Policy;
+
+/*
+https://adventofcode.com/2024/day/2
+*/
+
+/// The levels of each report, a line each.
+pub fn parse_reports(contents: &str) -> ParseResult<Vec<Vec<u32>>> {
+    let input = Input::new(2, contents);
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|report| {
+            report
+                .split_whitespace()
+                .map(|s| input.parse::<u32>(s))
+                .collect()
+        })
+        .collect()
+}
+
+fn is_valid_report(policy: &Policy, levels: &[u32]) -> bool {
+    policy.is_safe(levels)
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_reports(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let policy = Policy::puzzle();
+    let mut save_reports: u32 = 0;
+    for report in timed_parse(|| parse_reports(contents))? {
+        if is_valid_report(&policy, &report) {
+            save_reports += 1;
+        }
+    }
+    Ok(save_reports.into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let policy = Policy::puzzle();
+    let mut save_reports: u32 = 0;
+    for report in timed_parse(|| parse_reports(contents))? {
+        if dampener::dampen(&policy, &report, 1).is_some() {
+            save_reports += 1;
+        }
+    }
+    Ok(save_reports.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(2));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(4));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 50);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
@@ -0,0 +1,289 @@
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::path::Path;
+
+/*
+Report policies: which reports count as safe, built from independent constraints.
+
+Each constraint looks at the levels of a report on its own: the range of every level, the size of
+every step, the direction the levels go in, and how many equal levels may follow each other. The
+puzzle's policy is steps of 1 to 3 in one direction; a step of 0 is out of bounds there, so it needs
+no plateau limit.
+
+Policies can be named in a TOML file, each with any of the constraints:
+
+    [gentle]
+    levels = { min = 1, max = 99 }
+    steps = { min = 0, max = 2 }
+    direction = "increasing"  # or "decreasing", or "either" for one of the two
+    max_plateau = 2
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    /// No level below the one before it
+    Increasing,
+    /// No level above the one before it
+    Decreasing,
+    /// Increasing or decreasing throughout
+    Either,
+}
+
+/// An inclusive range; either end may be left out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Bounds {
+    #[serde(default)]
+    pub min: u32,
+    #[serde(default = "unbounded")]
+    pub max: u32,
+}
+
+fn unbounded() -> u32 {
+    u32::MAX
+}
+
+impl Bounds {
+    /// Any value at all.
+    pub const ALL: Bounds = Bounds { min: 0, max: u32::MAX };
+
+    pub fn contains(&self, value: u32) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Constraint {
+    /// Every level within the bounds.
+    Levels(Bounds),
+    /// Every difference between neighbouring levels within the bounds.
+    Steps(Bounds),
+    Direction(Direction),
+    /// At most this many equal levels in a row.
+    MaxPlateau(usize),
+}
+
+/// The index of the second level of the first pair of neighbours that is `broken`.
+fn first_step(levels: &[u32], mut broken: impl FnMut(u32, u32) -> bool) -> Option<usize> {
+    levels.windows(2).position(|pair| broken(pair[0], pair[1])).map(|i| i + 1)
+}
+
+impl Constraint {
+    /// The index of the first level that breaks the constraint.
+    pub fn check(&self, levels: &[u32]) -> Option<usize> {
+        match *self {
+            Constraint::Levels(bounds) => levels.iter().position(|&level| !bounds.contains(level)),
+            Constraint::Steps(bounds) => first_step(levels, |a, b| !bounds.contains(a.abs_diff(b))),
+            Constraint::Direction(Direction::Increasing) => first_step(levels, |a, b| b < a),
+            Constraint::Direction(Direction::Decreasing) => first_step(levels, |a, b| b > a),
+            Constraint::Direction(Direction::Either) => {
+                // The first step that isn't flat sets the direction.
+                let mut rising = None;
+                first_step(levels, |a, b| b != a && *rising.get_or_insert(b > a) != (b > a))
+            }
+            Constraint::MaxPlateau(max) => {
+                let mut run = 1;
+                first_step(levels, |a, b| {
+                    run = if a == b { run + 1 } else { 1 };
+                    run > max
+                })
+            }
+        }
+    }
+}
+
+impl Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Constraint::Levels(bounds) => write!(f, "levels from {} to {}", bounds.min, bounds.max),
+            Constraint::Steps(bounds) => write!(f, "steps of {} to {}", bounds.min, bounds.max),
+            Constraint::Direction(Direction::Increasing) => write!(f, "increasing levels"),
+            Constraint::Direction(Direction::Decreasing) => write!(f, "decreasing levels"),
+            Constraint::Direction(Direction::Either) => write!(f, "levels in one direction"),
+            Constraint::MaxPlateau(1) => write!(f, "no repeated levels"),
+            Constraint::MaxPlateau(max) => write!(f, "at most {} equal levels in a row", max),
+        }
+    }
+}
+
+/// Where a report first breaks its policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub constraint: Constraint,
+    /// Index of the offending level.
+    pub index: usize,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "level {} breaks {}", self.index + 1, self.constraint)
+    }
+}
+
+/// A report is safe when it meets every constraint.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+#[serde(try_from = "PolicyConfig")]
+pub struct Policy {
+    pub constraints: Vec<Constraint>,
+}
+
+impl Policy {
+    /// The puzzle's rule: steps of 1 to 3, all in the same direction.
+    pub fn puzzle() -> Self {
+        Self {
+            constraints: vec![
+                Constraint::Steps(Bounds { min: 1, max: 3 }),
+                Constraint::Direction(Direction::Either),
+            ],
+        }
+    }
+
+    pub fn is_safe(&self, levels: &[u32]) -> bool {
+        self.constraints.iter().all(|constraint| constraint.check(levels).is_none())
+    }
+
+    /// The earliest level that breaks a constraint; by the order of the constraints on a tie.
+    pub fn classify(&self, levels: &[u32]) -> Result<(), Violation> {
+        let violations = self.constraints.iter().filter_map(|&constraint| {
+            constraint.check(levels).map(|index| Violation { constraint, index })
+        });
+        match violations.min_by_key(|violation| violation.index) {
+            Some(violation) => Err(violation),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A policy as written in a file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicyConfig {
+    levels: Option<Bounds>,
+    steps: Option<Bounds>,
+    direction: Option<Direction>,
+    max_plateau: Option<usize>,
+}
+
+impl TryFrom<PolicyConfig> for Policy {
+    type Error = String;
+
+    fn try_from(config: PolicyConfig) -> Result<Self, String> {
+        for (name, bounds) in [("levels", config.levels), ("steps", config.steps)] {
+            if let Some(Bounds { min, max }) = bounds.filter(|bounds| bounds.min > bounds.max) {
+                return Err(format!("`{}` has a min of {} above its max of {}", name, min, max));
+            }
+        }
+        if config.max_plateau == Some(0) {
+            return Err("`max_plateau` must be at least 1".to_string());
+        }
+        let constraints = [
+            config.levels.map(Constraint::Levels),
+            config.steps.map(Constraint::Steps),
+            config.direction.map(Constraint::Direction),
+            config.max_plateau.map(Constraint::MaxPlateau),
+        ];
+        Ok(Self {
+            constraints: constraints.into_iter().flatten().collect(),
+        })
+    }
+}
+
+/// Named policies from the text of a policy file.
+pub fn parse_policies(contents: &str) -> Result<BTreeMap<String, Policy>> {
+    let policies: BTreeMap<String, Policy> = toml::from_str(contents)?;
+    if policies.is_empty() {
+        bail!("No policies defined");
+    }
+    Ok(policies)
+}
+
+pub fn load_policies(path: &Path) -> Result<BTreeMap<String, Policy>> {
+    let contents = common::read_input(path)?;
+    parse_policies(&contents).with_context(|| format!("Invalid policy file {}", path.display()))
+}
+
+/// The policy called `name` in the policy file at `config`, where `puzzle` is built in.
+pub fn find_policy(config: Option<&Path>, name: &str) -> Result<Policy> {
+    let mut policies = match config {
+        Some(path) => load_policies(path)?,
+        None => Default::default(),
+    };
+    match policies.remove(name) {
+        Some(policy) => Ok(policy),
+        None if name == "puzzle" => Ok(Policy::puzzle()),
+        None => bail!("No policy named `{}`", name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POLICIES: &str = include_str!("../policies.toml");
+
+    fn first_violation(policy: &Policy, levels: &[u32]) -> Option<(String, usize)> {
+        policy.classify(levels).err().map(|v| (v.constraint.to_string(), v.index))
+    }
+
+    #[test]
+    fn test_puzzle_policy() {
+        let policy = Policy::puzzle();
+        let reports = [[7, 6, 4, 2, 1], [1, 2, 7, 8, 9], [9, 7, 6, 2, 1], [1, 3, 2, 4, 5], [8, 6, 4, 4, 1]];
+        let verdicts: Vec<_> = reports.iter().map(|levels| first_violation(&policy, levels)).collect();
+        assert_eq!(
+            verdicts,
+            [
+                None,
+                Some(("steps of 1 to 3".to_string(), 2)),
+                Some(("steps of 1 to 3".to_string(), 3)),
+                Some(("levels in one direction".to_string(), 2)),
+                Some(("steps of 1 to 3".to_string(), 3)),
+            ]
+        );
+        assert!(policy.is_safe(&[]) && policy.is_safe(&[5]));
+    }
+
+    #[test]
+    fn test_constraints() {
+        let levels = [4, 4, 4, 5, 5, 3];
+        assert_eq!(Constraint::MaxPlateau(2).check(&levels), Some(2));
+        assert_eq!(Constraint::MaxPlateau(3).check(&levels), None);
+        assert_eq!(Constraint::MaxPlateau(1).to_string(), "no repeated levels");
+        assert_eq!(Constraint::Direction(Direction::Increasing).check(&levels), Some(5));
+        assert_eq!(Constraint::Direction(Direction::Decreasing).check(&levels), Some(3));
+        assert_eq!(Constraint::Direction(Direction::Either).check(&levels), Some(5));
+        assert_eq!(Constraint::Direction(Direction::Either).check(&[4, 4, 3, 3, 1]), None);
+        assert_eq!(Constraint::Levels(Bounds { min: 4, max: 4 }).check(&levels), Some(3));
+        assert_eq!(Constraint::Steps(Bounds { min: 0, max: 1 }).check(&levels), Some(5));
+    }
+
+    #[test]
+    fn test_policy_file() {
+        let policies = parse_policies(POLICIES).unwrap();
+        assert_eq!(policies["puzzle"], Policy::puzzle());
+        let gentle = &policies["gentle"];
+        assert_eq!(gentle.constraints.len(), 4);
+        assert!(gentle.is_safe(&[1, 1, 3, 4, 4]));
+        assert_eq!(
+            gentle.classify(&[0, 1, 1, 1]).unwrap_err().to_string(),
+            "level 1 breaks levels from 1 to 99"
+        );
+        assert_eq!(
+            gentle.classify(&[5, 6, 6, 6]).unwrap_err().to_string(),
+            "level 4 breaks at most 2 equal levels in a row"
+        );
+    }
+
+    #[test]
+    fn test_rejects_bad_policies() {
+        let err = parse_policies("[a]\nsteps = { min = 3, max = 1 }\n").unwrap_err();
+        assert!(err.to_string().contains("`steps` has a min of 3 above its max of 1"), "{}", err);
+        assert!(parse_policies("[a]\nmax_plateau = 0\n").is_err());
+        assert!(parse_policies("[a]\ndirection = \"sideways\"\n").is_err());
+        assert!(parse_policies("[a]\nstep = { max = 3 }\n").is_err());
+        assert!(parse_policies("").is_err());
+    }
+}
@@ -0,0 +1,67 @@
+use common::generator::{GenOptions, Generated, Rng};
+
+/*
+Corrupted memory with `size` instructions (700 by default), six lines like the real input. Valid
+`mul`, `do()` and `don't()` instructions are separated by noise and near misses such as
+`mul[3,7]` or `mul(4*`. The noise has no `m` or `d`, so it can't complete an instruction.
+*/
+
+const NOISE: &[u8] = b"!@#$%^&*[]{}<>?,;:+-_ ~/'()xwhyr0123456789";
+
+fn noise(rng: &mut Rng, out: &mut String) {
+    for _ in 0..rng.usize(0..6) {
+        out.push(NOISE[rng.usize(..NOISE.len())] as char);
+    }
+}
+
+fn near_miss(rng: &mut Rng) -> String {
+    let (lhs, rhs) = (rng.u32(1..1000), rng.u32(1..1000));
+    match rng.u8(..5) {
+        0 => format!("mul[{},{}]", lhs, rhs),
+        1 => format!("mul({},{}]", lhs, rhs),
+        2 => format!("mul ( {},{} )", lhs, rhs),
+        3 => format!("mul({}*", lhs),
+        _ => "don't[]".to_string(),
+    }
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let instructions = options.size_or(700);
+    let per_line = instructions.div_ceil(6).max(1);
+    let (mut all, mut enabled) = (0u64, 0u64);
+    let mut allowed = true;
+
+    let mut input = String::new();
+    for i in 0..instructions {
+        if i > 0 && i % per_line == 0 {
+            input.push('\n');
+        }
+        noise(&mut rng, &mut input);
+        if rng.u8(..4) == 0 {
+            input += &near_miss(&mut rng);
+            noise(&mut rng, &mut input);
+        }
+        match rng.u8(..10) {
+            0 => {
+                input += "do()";
+                allowed = true;
+            }
+            1 => {
+                input += "don't()";
+                allowed = false;
+            }
+            _ => {
+                let (lhs, rhs) = (rng.u64(1..1000), rng.u64(1..1000));
+                input += &format!("mul({},{})", lhs, rhs);
+                all += lhs * rhs;
+                if allowed {
+                    enabled += lhs * rhs;
+                }
+            }
+        }
+    }
+    input.push('\n');
+
+    Generated::unknown(input).part1(all).part2(enabled)
+}
@@ -0,0 +1,91 @@
+use anyhow::{anyhow, Result};
+use common::{Answer, Input, ParseResult};
+use regex::Regex;
+use tracing::debug;
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/3
+*/
+
+fn multiply(input: &Input, instruction: &str) -> ParseResult<u64> {
+    let arguments = instruction
+        .strip_prefix("mul(")
+        .and_then(|rest| rest.strip_suffix(")"))
+        .and_then(|rest| rest.split_once(","));
+    let Some((lhs, rhs)) = arguments else {
+        return Err(input.error(instruction, "expected `mul(X,Y)`"));
+    };
+    let lhs: u32 = input.parse(lhs)?;
+    let rhs: u32 = input.parse(rhs)?;
+    Ok(u64::from(lhs) * u64::from(rhs))
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    // Scanning for instructions is the whole solution, part 2 sees every kind of them.
+    part2(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let input = Input::new(3, contents);
+    let mut result: u64 = 0;
+    let re = Regex::new(r"mul\(\d+,\d+\)").unwrap();
+    for mat in re.find_iter(contents) {
+        debug!("Match found: {}", mat.as_str());
+        result = result
+            .checked_add(multiply(&input, mat.as_str())?)
+            .ok_or_else(|| anyhow!("The sum of the products overflows"))?;
+    }
+    Ok(result.into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let input = Input::new(3, contents);
+    let mut result: u64 = 0;
+    let mut mult_allowed: bool = true;
+    let re = Regex::new(r"mul\(\d+,\d+\)|do\(\)|don't\(\)").unwrap();
+    for mat in re.find_iter(contents) {
+        debug!("Match found: {}", mat.as_str());
+        if mat.as_str().starts_with("mul") {
+            if mult_allowed {
+                result = result
+                    .checked_add(multiply(&input, mat.as_str())?)
+                    .ok_or_else(|| anyhow!("The sum of the products overflows"))?;
+            }
+        } else if mat.as_str().starts_with("don't") {
+            mult_allowed = false;
+        } else if mat.as_str().starts_with("do") {
+            mult_allowed = true;
+        }
+    }
+    Ok(result.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(161));
+    }
+
+    #[test]
+    fn test_example2_part2() {
+        assert_eq!(part2(EXAMPLE_2).unwrap(), Answer::Number(48));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 100);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
@@ -0,0 +1,65 @@
+use common::generator::{render, GenOptions, Generated};
+
+/*
+A `size` x `size` word search (140 by default) over the letters of XMAS, with some extra copies of
+the words written in. The answers come from reading every row, column and diagonal as a string.
+*/
+
+fn count_in_lines(rows: &[Vec<u8>]) -> usize {
+    let n = rows.len() as isize;
+    let at = |x: isize, y: isize| rows[y as usize][x as usize];
+    let mut lines: Vec<Vec<u8>> = rows.to_vec();
+    lines.extend((0..n).map(|x| (0..n).map(|y| at(x, y)).collect()));
+    for start in -(n - 1)..n {
+        let cells = (0..n).filter(|y| (0..n).contains(&(start + y)));
+        lines.push(cells.clone().map(|y| at(start + y, y)).collect());
+        lines.push(cells.map(|y| at(n - 1 - (start + y), y)).collect());
+    }
+    lines
+        .iter()
+        .map(|line| {
+            let line = String::from_utf8_lossy(line);
+            line.matches("XMAS").count() + line.matches("SAMX").count()
+        })
+        .sum()
+}
+
+fn count_crosses(rows: &[Vec<u8>]) -> usize {
+    let mas = |a: u8, b: u8| (a, b) == (b'M', b'S') || (a, b) == (b'S', b'M');
+    let n = rows.len();
+    let mut count = 0;
+    for y in 1..n.saturating_sub(1) {
+        for x in 1..n - 1 {
+            if rows[y][x] == b'A'
+                && mas(rows[y - 1][x - 1], rows[y + 1][x + 1])
+                && mas(rows[y - 1][x + 1], rows[y + 1][x - 1])
+            {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let n = options.size_or(140).max(4);
+
+    let mut rows: Vec<Vec<u8>> = (0..n)
+        .map(|_| (0..n).map(|_| b"XMAS"[rng.usize(..4)]).collect())
+        .collect();
+    for _ in 0..n * n / 20 {
+        let (dx, dy) = [(1, 0), (0, 1), (1, 1), (1, -1)][rng.usize(..4)];
+        let word: &[u8] = if rng.bool() { b"XMAS" } else { b"SAMX" };
+        let x = rng.isize(0..=n as isize - 4);
+        let y = if dy < 0 { rng.isize(3..n as isize) } else { rng.isize(0..=n as isize - 4) };
+        for (i, &c) in word.iter().enumerate() {
+            let i = i as isize;
+            rows[(y + dy * i) as usize][(x + dx * i) as usize] = c;
+        }
+    }
+
+    let input = render(&rows);
+    let (words, crosses) = (count_in_lines(&rows), count_crosses(&rows));
+    Generated::unknown(input).part1(words).part2(crosses)
+}
@@ -0,0 +1,99 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+use grid::{Grid, Pos, OFFSETS8};
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/4
+*/
+
+fn parse_grid(contents: &str) -> ParseResult<Grid<char>> {
+    let input = Input::new(4, contents);
+    let lines = input.grid_lines()?;
+    Ok(Grid::from_lines(&lines, |c| c))
+}
+
+fn search_word(grid: &Grid<char>, word: &str) -> u32 {
+    let mut count: u32 = 0;
+
+    for pos in grid.positions() {
+        for offset in OFFSETS8 {
+            let mut cell = pos;
+            let mut found = true;
+            for word_char in word.chars() {
+                if grid.get(cell) != Some(&word_char) {
+                    found = false;
+                    break;
+                }
+                cell = cell + offset;
+            }
+            if found {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn is_x_mas(grid: &Grid<char>, pos: Pos) -> bool {
+    if grid[pos] != 'A' {
+        return false;
+    }
+
+    let corner = |dx, dy| grid.get(pos + Pos::new(dx, dy));
+    let ul = corner(-1, -1);
+    let dr = corner(1, 1);
+    let ur = corner(1, -1);
+    let dl = corner(-1, 1);
+
+    if !(ul == Some(&'M') && dr == Some(&'S') || ul == Some(&'S') && dr == Some(&'M')) {
+        return false;
+    }
+
+    ur == Some(&'M') && dl == Some(&'S') || ur == Some(&'S') && dl == Some(&'M')
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_grid(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let word = "XMAS";
+    let grid = timed_parse(|| parse_grid(contents))?;
+    let count = search_word(&grid, word);
+    Ok(count.into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let grid = timed_parse(|| parse_grid(contents))?;
+    let count = grid.positions().filter(|&pos| is_x_mas(&grid, pos)).count();
+    Ok(count.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(18));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(9));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 12);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
@@ -0,0 +1,53 @@
+use common::generator::{GenOptions, Generated};
+
+/*
+A hidden order of 49 pages and a rule for every pair of them, followed by `size` updates (200 by
+default) of 5 to 23 pages. Half of the updates keep the hidden order and the rest are shuffled, so
+both middle-page sums are read off the hidden order.
+*/
+
+const PAGES: usize = 49;
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let updates = options.size_or(200);
+
+    let mut order: Vec<u32> = (10..100).collect();
+    rng.shuffle(&mut order);
+    order.truncate(PAGES);
+
+    let mut rules = Vec::new();
+    for (i, before) in order.iter().enumerate() {
+        for after in &order[i + 1..] {
+            rules.push(format!("{}|{}\n", before, after));
+        }
+    }
+    rng.shuffle(&mut rules);
+    let mut input: String = rules.concat();
+    input.push('\n');
+
+    let (mut ordered, mut reordered) = (0u64, 0u64);
+    for _ in 0..updates {
+        let len = 2 * rng.usize(2..=11) + 1;
+        let mut picked: Vec<usize> = (0..PAGES).collect();
+        rng.shuffle(&mut picked);
+        picked.truncate(len);
+        picked.sort_unstable();
+        let middle = u64::from(order[picked[len / 2]]);
+
+        if rng.bool() {
+            ordered += middle;
+        } else {
+            let sorted = picked.clone();
+            while picked == sorted {
+                rng.shuffle(&mut picked);
+            }
+            reordered += middle;
+        }
+        let pages: Vec<String> = picked.iter().map(|&i| order[i].to_string()).collect();
+        input += &pages.join(",");
+        input.push('\n');
+    }
+
+    Generated::unknown(input).part1(ordered).part2(reordered)
+}
@@ -0,0 +1,201 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+use tracing::debug;
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/5
+*/
+
+#[derive(Debug)]
+struct OrderingRule {
+    before: u32,
+    after: u32,
+}
+
+#[derive(Debug)]
+struct Sequence {
+    elements: Vec<u32>,
+}
+
+#[derive(Debug)]
+struct InputData {
+    ordering_rules: Vec<OrderingRule>,
+    sequences: Vec<Sequence>,
+}
+
+#[allow(dead_code)]
+fn slice_before_index<T>(vec: &[T], index: usize) -> &[T] {
+    let (before, _) = vec.split_at(index);
+    before
+}
+
+fn get_after_rules(input: u32, ordering_rules: &[OrderingRule]) -> Vec<u32> {
+    ordering_rules
+        .iter()
+        .filter_map(|rule| {
+            if rule.before == input {
+                Some(rule.after)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn get_before_rules(input: u32, ordering_rules: &[OrderingRule]) -> Vec<u32> {
+    ordering_rules
+        .iter()
+        .filter_map(|rule| {
+            if rule.after == input {
+                Some(rule.before)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn get_middle_element<T>(vec: &[T]) -> Option<&T> {
+    if vec.is_empty() {
+        None
+    } else {
+        let middle_index = vec.len() / 2;
+        vec.get(middle_index)
+    }
+}
+
+fn check_sequence(sequence: &Sequence, ordering_rules: &[OrderingRule]) -> bool {
+    for i in 0..sequence.elements.len() {
+        let elem = sequence.elements[i];
+        let after_rules = get_after_rules(elem, ordering_rules);
+        if after_rules.is_empty() {
+            continue;
+        }
+        let before_rules = get_before_rules(elem, ordering_rules);
+        if before_rules.is_empty() {
+            continue;
+        }
+
+        let (vec_before, vec_after) = sequence.elements.split_at(i);
+        //check if vec_after contains before
+        for rule in before_rules {
+            if vec_after.contains(&rule) {
+                return false;
+            }
+        }
+        for rule in after_rules {
+            if vec_before.contains(&rule) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn reorder_sequence(sequence: &mut [u32], ordering_rules: &[OrderingRule]) {
+    sequence.sort_by(|a, b| {
+        for rule in ordering_rules {
+            if rule.before == *a && rule.after == *b {
+                return std::cmp::Ordering::Less;
+            } else if rule.before == *b && rule.after == *a {
+                return std::cmp::Ordering::Greater;
+            }
+        }
+        std::cmp::Ordering::Equal
+    });
+}
+
+fn parse_input(contents: &str) -> ParseResult<InputData> {
+    let input = Input::new(5, contents);
+    let mut ordering_rules: Vec<OrderingRule> = Vec::new();
+    let mut sequences: Vec<Sequence> = Vec::new();
+    let mut is_reading_rules = true;
+
+    for line in contents.lines() {
+        if line.trim().is_empty() {
+            is_reading_rules = false;
+            continue;
+        }
+
+        if is_reading_rules {
+            let Some((before, after)) = line.split_once("|") else {
+                return Err(input.error(line, "expected an ordering rule `X|Y`"));
+            };
+            let before = input.parse::<u32>(before)?;
+            let after = input.parse::<u32>(after)?;
+            ordering_rules.push(OrderingRule { before, after });
+        } else {
+            let elements: Vec<u32> = line
+                .split(',')
+                .map(|s| input.parse::<u32>(s.trim()))
+                .collect::<ParseResult<Vec<u32>>>()?;
+            sequences.push(Sequence { elements });
+        }
+    }
+
+    Ok(InputData {
+        ordering_rules,
+        sequences,
+    })
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_input(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let input_data = timed_parse(|| parse_input(contents))?;
+    let mut sum: u32 = 0;
+    for sequence in &input_data.sequences {
+        if check_sequence(sequence, &input_data.ordering_rules) {
+            debug!("Sequence {:?} follows the rules", sequence.elements);
+            sum += get_middle_element(&sequence.elements).expect("No middle element found");
+        } else {
+            debug!("Sequence {:?} does not follow the rules", sequence.elements);
+        }
+    }
+    Ok(sum.into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let mut input_data = timed_parse(|| parse_input(contents))?;
+    let mut sum: u32 = 0;
+    for sequence in &mut input_data.sequences {
+        if !check_sequence(sequence, &input_data.ordering_rules) {
+            reorder_sequence(&mut sequence.elements, &input_data.ordering_rules);
+            if let Some(middle) = get_middle_element(&sequence.elements) {
+                sum += *middle;
+            }
+        }
+    }
+    Ok(sum.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(143));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(123));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 20);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
@@ -0,0 +1,57 @@
+use common::generator::{render, GenOptions, Generated, Rng};
+use std::collections::HashSet;
+
+/*
+A `size` x `size` lab (130 by default) with about one cell in twenty obstructed and the guard on
+a free cell. Maps are drawn again until the guard walks off the map, which is what part 1 counts.
+*/
+
+fn draw(rng: &mut Rng, n: usize) -> Vec<Vec<u8>> {
+    let mut rows: Vec<Vec<u8>> = (0..n)
+        .map(|_| (0..n).map(|_| if rng.u8(..20) == 0 { b'#' } else { b'.' }).collect())
+        .collect();
+    loop {
+        let (x, y) = (rng.usize(..n), rng.usize(..n));
+        if rows[y][x] == b'.' {
+            rows[y][x] = b'^';
+            return rows;
+        }
+    }
+}
+
+/// Cells the guard walks before leaving the map, or `None` when the walk never ends.
+fn walk(rows: &[Vec<u8>]) -> Option<usize> {
+    let n = rows.len() as isize;
+    let y = rows.iter().position(|row| row.contains(&b'^'))?;
+    let x = rows[y].iter().position(|&c| c == b'^')?;
+    let (mut x, mut y, mut dx, mut dy) = (x as isize, y as isize, 0, -1);
+    let mut cells = HashSet::new();
+    let mut states = HashSet::new();
+    loop {
+        cells.insert((x, y));
+        if !states.insert((x, y, dx, dy)) {
+            return None;
+        }
+        let (nx, ny) = (x + dx, y + dy);
+        if !(0..n).contains(&nx) || !(0..n).contains(&ny) {
+            return Some(cells.len());
+        }
+        if rows[ny as usize][nx as usize] == b'#' {
+            (dx, dy) = (-dy, dx);
+        } else {
+            (x, y) = (nx, ny);
+        }
+    }
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let n = options.size_or(130).max(2);
+    loop {
+        let rows = draw(&mut rng, n);
+        if let Some(visited) = walk(&rows) {
+            let input = render(&rows);
+            return Generated::unknown(input).part1(visited);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+use grid::{Dir, Grid, Pos};
+use std::collections::HashSet;
+use tracing::{trace, trace_span};
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/6
+*/
+
+fn simulate_guard(
+    grid: &Grid<char>,
+    start_position: Pos,
+    allow_out_of_bounds: bool,
+) -> Result<HashSet<Pos>> {
+    let mut direction = Dir::N;
+    let mut visited_positions: HashSet<Pos> = HashSet::new();
+    let mut visited_with_direction: HashSet<(Pos, Dir)> = HashSet::new();
+    let mut position = start_position;
+    visited_positions.insert(position);
+    visited_with_direction.insert((position, direction));
+
+    loop {
+        let next_position = position.step(direction);
+
+        match grid.get(next_position) {
+            None => {
+                if allow_out_of_bounds {
+                    break;
+                } else {
+                    return Err(anyhow::anyhow!("Out of bounds"));
+                }
+            }
+            Some('#') => direction = direction.turn_right(),
+            Some(_) => {
+                position = next_position;
+                if !visited_with_direction.insert((position, direction)) {
+                    break; // Loop detected
+                }
+                visited_positions.insert(position);
+            }
+        }
+    }
+
+    Ok(visited_positions)
+}
+
+fn parse_grid(contents: &str) -> ParseResult<(Grid<char>, Pos)> {
+    let input = Input::new(6, contents);
+    let lines = input.grid_lines()?;
+    let grid = Grid::from_lines(&lines, |c| c);
+    let start_position = grid
+        .find(&'^')
+        .ok_or_else(|| input.eof_error("expected the guard `^` somewhere in the map"))?;
+    Ok((grid, start_position))
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_grid(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let (grid, start_position) = timed_parse(|| parse_grid(contents))?;
+    let visited_positions = simulate_guard(&grid, start_position, true)?;
+
+    Ok(visited_positions.len().into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let (mut grid, start_position) = timed_parse(|| parse_grid(contents))?;
+    let visited_positions = simulate_guard(&grid, start_position, true)?;
+
+    let allow_out_of_bounds = false;
+
+    let mut loop_positions = HashSet::new();
+
+    for &position in &visited_positions {
+        if position != start_position {
+            let _trial = trace_span!("obstruction", %position).entered();
+            grid[position] = '#';
+            let loops = simulate_guard(&grid, start_position, allow_out_of_bounds).is_ok();
+            trace!(loops);
+            if loops {
+                loop_positions.insert(position);
+            }
+        }
+        grid[position] = '.';
+    }
+
+    Ok(loop_positions.len().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(41));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(6));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 20);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
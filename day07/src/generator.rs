@@ -0,0 +1,75 @@
+use common::generator::{GenOptions, Generated, Rng};
+
+/*
+`size` equations (850 by default) of 2 to 12 numbers, with test values built from random
+operators. A third of them are nudged off so they may have no solution. Numbers have at most 14
+digits in total, so even concatenating all of them, and summing the results, fits a u64. A pruned search over the operators
+decides which equations count for each part.
+*/
+
+fn concat(lhs: u64, rhs: u64) -> u64 {
+    lhs * 10u64.pow(rhs.to_string().len() as u32) + rhs
+}
+
+fn solvable(test_value: u64, value: u64, rest: &[u64], with_concat: bool) -> bool {
+    let Some((&next, rest)) = rest.split_first() else {
+        return value == test_value;
+    };
+    if value > test_value {
+        return false;
+    }
+    solvable(test_value, value + next, rest, with_concat)
+        || solvable(test_value, value * next, rest, with_concat)
+        || with_concat && solvable(test_value, concat(value, next), rest, with_concat)
+}
+
+fn numbers(rng: &mut Rng) -> Vec<u64> {
+    let mut numbers = Vec::new();
+    let mut digits = 0;
+    for _ in 0..rng.usize(2..=12) {
+        let number = match rng.u8(..4) {
+            0 => rng.u64(100..1000),
+            1 => rng.u64(10..100),
+            _ => rng.u64(1..10),
+        };
+        digits += number.to_string().len();
+        if digits > 14 {
+            break;
+        }
+        numbers.push(number);
+    }
+    numbers
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let equations = options.size_or(850);
+    let (mut calibration, mut with_concat) = (0u64, 0u64);
+
+    let mut input = String::new();
+    for _ in 0..equations {
+        let numbers = numbers(&mut rng);
+        let mut test_value = numbers[0];
+        for &number in &numbers[1..] {
+            test_value = match rng.u8(..5) {
+                0 | 1 => test_value + number,
+                2 | 3 => test_value * number,
+                _ => concat(test_value, number),
+            };
+        }
+        if rng.u8(..3) == 0 {
+            test_value += rng.u64(1..10);
+        }
+
+        if solvable(test_value, numbers[0], &numbers[1..], false) {
+            calibration += test_value;
+        }
+        if solvable(test_value, numbers[0], &numbers[1..], true) {
+            with_concat += test_value;
+        }
+        let numbers: Vec<String> = numbers.iter().map(u64::to_string).collect();
+        input += &format!("{}: {}\n", test_value, numbers.join(" "));
+    }
+
+    Generated::unknown(input).part1(calibration).part2(with_concat)
+}
@@ -0,0 +1,158 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/7
+*/
+
+/// Whether some choice of operators makes `numbers` evaluate to `test_value`. Works backwards
+/// from the last number: `+` must have added it, `*` multiplied by it, and `||` appended its
+/// digits, which leaves a smaller target for the numbers before it each time.
+fn find_valid_expression(numbers: &[u64], allowed_operators: &[char], test_value: u64) -> bool {
+    let Some((&last, rest)) = numbers.split_last() else {
+        return false;
+    };
+    if rest.is_empty() {
+        return last == test_value;
+    }
+
+    allowed_operators.iter().any(|&op| {
+        let target = match op {
+            '+' => test_value.checked_sub(last),
+            // Anything times zero is zero
+            '*' if last == 0 => return test_value == 0,
+            '*' => test_value.is_multiple_of(last).then(|| test_value / last),
+            '|' => {
+                let shift = 10u64.checked_pow(last.checked_ilog10().unwrap_or(0) + 1);
+                shift.filter(|&shift| test_value % shift == last).map(|shift| test_value / shift)
+            }
+            _ => panic!("Unknown operator"),
+        };
+        target.is_some_and(|target| find_valid_expression(rest, allowed_operators, target))
+    })
+}
+
+fn parse_equations(contents: &str) -> ParseResult<Vec<(u64, Vec<u64>)>> {
+    let input = Input::new(7, contents);
+    let mut equations = Vec::new();
+
+    for line in contents.lines().filter(|line| !line.is_empty()) {
+        let Some((test_value, numbers)) = line.split_once(":") else {
+            return Err(input.error(line, "expected `test value: numbers`"));
+        };
+        let test_value: u64 = input.parse(test_value)?;
+        let numbers: Vec<u64> = numbers
+            .split_whitespace()
+            .map(|s| input.parse(s))
+            .collect::<ParseResult<_>>()?;
+        if numbers.is_empty() {
+            return Err(input.error(line, "expected at least one number after `:`"));
+        }
+        equations.push((test_value, numbers));
+    }
+
+    Ok(equations)
+}
+
+fn total_calibration_result(equations: &[(u64, Vec<u64>)], allowed_operators: &[char]) -> u64 {
+    let mut total_calibration_result = 0;
+
+    for (test_value, numbers) in equations {
+        if find_valid_expression(numbers, allowed_operators, *test_value) {
+            total_calibration_result += test_value;
+        }
+    }
+
+    total_calibration_result
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_equations(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let equations = timed_parse(|| parse_equations(contents))?;
+    Ok(total_calibration_result(&equations, &['+', '*']).into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let equations = timed_parse(|| parse_equations(contents))?;
+    Ok(total_calibration_result(&equations, &['+', '*', '|']).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(3749));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(11387));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 30);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+
+    #[test]
+    fn test_concatenation_overflow() {
+        // Used to panic: the concatenation doesn't fit a u64
+        assert_eq!(part2("1: 99999999999 99999999999\n").unwrap(), Answer::Number(0));
+    }
+
+    /// Tries every assignment of operators, evaluated left to right.
+    fn brute_force(numbers: &[u64], allowed_operators: &[char], test_value: u64) -> bool {
+        let slots = (numbers.len() - 1) as u32;
+        (0..allowed_operators.len().pow(slots)).any(|mut choice| {
+            let mut result = Some(numbers[0]);
+            for &number in &numbers[1..] {
+                let op = allowed_operators[choice % allowed_operators.len()];
+                choice /= allowed_operators.len();
+                result = result.and_then(|result| match op {
+                    '+' => result.checked_add(number),
+                    '*' => result.checked_mul(number),
+                    _ => format!("{}{}", result, number).parse().ok(),
+                });
+            }
+            result == Some(test_value)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_brute_force(numbers in vec(0u64..50, 1..7), ops in vec(0usize..3, 6), noise in 0u64..3) {
+            // A reachable test value, sometimes nudged off it
+            let mut planted = numbers[0];
+            for (&number, &op) in numbers[1..].iter().zip(&ops) {
+                planted = match op {
+                    0 => planted + number,
+                    1 => planted * number,
+                    _ => format!("{}{}", planted, number).parse().unwrap(),
+                };
+            }
+            let test_value = planted + noise;
+            for allowed in [&['+', '*'][..], &['+', '*', '|']] {
+                prop_assert_eq!(
+                    find_valid_expression(&numbers, allowed, test_value),
+                    brute_force(&numbers, allowed, test_value)
+                );
+            }
+        }
+    }
+}
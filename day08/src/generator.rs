@@ -0,0 +1,59 @@
+use common::generator::{render, GenOptions, Generated};
+
+/*
+A `size` x `size` map (50 by default) with about one antenna in fourteen cells, four per
+frequency. The answers check every cell against every pair of antennas of a frequency, straight
+from the puzzle's definitions.
+*/
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+type Point = (i64, i64);
+
+fn is_antinode(p: Point, a: Point, b: Point) -> bool {
+    p == (2 * a.0 - b.0, 2 * a.1 - b.1) || p == (2 * b.0 - a.0, 2 * b.1 - a.1)
+}
+
+/// Whether `p` is `a` plus a whole multiple of the step from `a` to `b`.
+fn is_harmonic(p: Point, a: Point, b: Point) -> bool {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let (px, py) = (p.0 - a.0, p.1 - a.1);
+    px * dy == py * dx && (dx == 0 || px % dx == 0) && (dy == 0 || py % dy == 0)
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let n = options.size_or(50).max(2);
+
+    let mut rows = vec![vec![b'.'; n]; n];
+    let mut antennas: Vec<Vec<Point>> = Vec::new();
+    let mut cells: Vec<usize> = (0..n * n).collect();
+    rng.shuffle(&mut cells);
+    for (i, &cell) in cells.iter().take(n * n / 14).enumerate() {
+        let frequency = (i / 4) % FREQUENCIES.len();
+        if antennas.len() == frequency {
+            antennas.push(Vec::new());
+        }
+        rows[cell / n][cell % n] = FREQUENCIES[frequency];
+        antennas[frequency].push(((cell % n) as i64, (cell / n) as i64));
+    }
+
+    let pairs: Vec<(Point, Point)> = antennas
+        .iter()
+        .flat_map(|same| {
+            same.iter()
+                .enumerate()
+                .flat_map(move |(i, &a)| same[i + 1..].iter().map(move |&b| (a, b)))
+        })
+        .collect();
+    let (mut antinodes, mut harmonics) = (0u64, 0u64);
+    for y in 0..n as i64 {
+        for x in 0..n as i64 {
+            antinodes += pairs.iter().any(|&(a, b)| is_antinode((x, y), a, b)) as u64;
+            harmonics += pairs.iter().any(|&(a, b)| is_harmonic((x, y), a, b)) as u64;
+        }
+    }
+
+    let input = render(&rows);
+    Generated::unknown(input).part1(antinodes).part2(harmonics)
+}
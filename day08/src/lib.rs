@@ -0,0 +1,117 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+use std::collections::{HashMap, HashSet};
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/8
+*/
+
+type Point = (isize, isize);
+
+fn node_diff(a: Point, b: Point) -> Point {
+    (a.0 - b.0, a.1 - b.1)
+}
+
+type Antennas = HashMap<char, HashSet<Point>>;
+
+fn parse_input(contents: &str) -> ParseResult<(Antennas, HashMap<Point, usize>)> {
+    let input = Input::new(8, contents);
+    let mut nodes: Antennas = HashMap::new();
+    let mut indices: HashMap<Point, usize> = HashMap::new();
+
+    for (i, row) in input.grid_lines()?.into_iter().enumerate() {
+        for (j, c) in row.chars().enumerate() {
+            indices.insert((i as isize, j as isize), 0);
+            if c != '.' {
+                nodes
+                    .entry(c)
+                    .or_default()
+                    .insert((i as isize, j as isize));
+            }
+        }
+    }
+
+    Ok((nodes, indices))
+}
+
+fn calculate_antinodes(
+    nodes: &HashMap<char, HashSet<Point>>,
+    indices: &HashMap<Point, usize>,
+) -> (HashSet<Point>, HashSet<Point>) {
+    let mut uniq: HashSet<Point> = HashSet::new();
+    let mut uniq2: HashSet<Point> = HashSet::new();
+
+    for antennas in nodes.values() {
+        for &a in antennas {
+            uniq2.insert(a);
+            for &b in antennas {
+                if a == b {
+                    continue;
+                }
+                let d = node_diff(a, b);
+                let mut node_ = (a.0 + d.0, a.1 + d.1);
+                if indices.contains_key(&node_) {
+                    uniq.insert(node_);
+                    uniq2.insert(node_);
+                    loop {
+                        node_ = (node_.0 + d.0, node_.1 + d.1);
+                        if indices.contains_key(&node_) {
+                            uniq2.insert(node_);
+                        } else {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    (uniq, uniq2)
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_input(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let (nodes, indices) = timed_parse(|| parse_input(contents))?;
+    let (uniq, _uniq2) = calculate_antinodes(&nodes, &indices);
+
+    Ok(uniq.len().into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let (nodes, indices) = timed_parse(|| parse_input(contents))?;
+    let (_uniq, uniq2) = calculate_antinodes(&nodes, &indices);
+
+    Ok(uniq2.len().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(14));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(34));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 20);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
@@ -0,0 +1,54 @@
+use common::generator::{GenOptions, Generated};
+
+/*
+A disk map of `size` digits (19999 by default): files of 1 to 9 blocks, free spans of 0 to 9.
+Part 1 is checked with a two-pointer compaction of the blocks; the whole-file compaction of part
+2 is left to the solver. Checksums that don't fit a u64 are not planted.
+*/
+
+fn compacted_checksum(digits: &[u8]) -> Option<u64> {
+    let mut blocks: Vec<Option<u64>> = Vec::new();
+    for (i, &digit) in digits.iter().enumerate() {
+        let block = (i % 2 == 0).then_some(i as u64 / 2);
+        blocks.extend(std::iter::repeat_n(block, usize::from(digit - b'0')));
+    }
+
+    let (mut free, mut last) = (0, blocks.len());
+    loop {
+        while free < blocks.len() && blocks[free].is_some() {
+            free += 1;
+        }
+        while last > 0 && blocks[last - 1].is_none() {
+            last -= 1;
+        }
+        if free >= last {
+            break;
+        }
+        blocks.swap(free, last - 1);
+    }
+
+    let checksum: u128 = blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(pos, id)| id.map(|id| pos as u128 * u128::from(id)))
+        .sum();
+    u64::try_from(checksum).ok()
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let digits: Vec<u8> = (0..options.size_or(19999).max(1))
+        .map(|i| match i % 2 {
+            0 => b'0' + rng.u8(1..=9),
+            _ => b'0' + rng.u8(0..=9),
+        })
+        .collect();
+
+    let mut input = String::from_utf8_lossy(&digits).into_owned();
+    input.push('\n');
+    let mut generated = Generated::unknown(input);
+    if let Some(checksum) = compacted_checksum(&digits) {
+        generated = generated.part1(checksum);
+    }
+    generated
+}
@@ -0,0 +1,261 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+use tracing::trace;
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/9
+*/
+
+fn parse_disk_map(contents: &str) -> ParseResult<Vec<Option<usize>>> {
+    let input = Input::new(9, contents);
+    let disk = contents.trim();
+    let mut disk_map = Vec::new();
+    let mut is_file = true;
+    let mut file_id = 0;
+
+    for (pos, c) in disk.char_indices() {
+        let length = c
+            .to_digit(10)
+            .ok_or_else(|| input.error(&disk[pos..pos + c.len_utf8()], "expected a digit"))?
+            as usize;
+        for _ in 0..length {
+            if is_file {
+                disk_map.push(Some(file_id));
+            } else {
+                disk_map.push(None);
+            }
+        }
+        if is_file {
+            file_id += 1;
+        }
+        is_file = !is_file;
+    }
+
+    Ok(disk_map)
+}
+
+fn get_free_blocks(disk_map: &[Option<usize>]) -> Vec<usize> {
+    let mut free_blocks = Vec::new();
+    for (pos, block) in disk_map.iter().enumerate() {
+        if block.is_none() {
+            free_blocks.push(pos);
+        }
+    }
+    free_blocks.reverse();
+    free_blocks
+}
+
+fn compact_blocks(mut disk_map: Vec<Option<usize>>) -> Vec<Option<usize>> {
+    // Move single blocks from the end of the disk into the leftmost free block
+    let mut free_blocks = get_free_blocks(&disk_map);
+    for read_pos in (0..disk_map.len()).rev() {
+        if disk_map[read_pos].is_none() {
+            continue;
+        }
+        match free_blocks.pop() {
+            Some(free_pos) if free_pos < read_pos => disk_map.swap(free_pos, read_pos),
+            _ => break,
+        }
+    }
+
+    disk_map
+}
+
+fn find_free_spans(disk_map: &[Option<usize>]) -> Vec<(usize, usize)> {
+    let mut free_spans = Vec::new();
+    let mut current_length = 0;
+    let mut start_pos = None;
+
+    for (pos, block) in disk_map.iter().enumerate() {
+        if block.is_none() {
+            if current_length == 0 {
+                start_pos = Some(pos);
+            }
+            current_length += 1;
+        } else {
+            if let Some(start) = start_pos {
+                free_spans.push((start, current_length));
+            }
+            current_length = 0;
+            start_pos = None;
+        }
+    }
+
+    // Add the last free span if it exists
+    if let Some(start) = start_pos {
+        free_spans.push((start, current_length));
+    }
+
+    free_spans
+}
+
+fn get_file_blocks(disk_map: &[Option<usize>]) -> Vec<(usize, usize)> {
+    let mut file_blocks: Vec<(usize, usize)> = Vec::new(); // (file_id, length)
+    let mut current_file_id = None;
+    let mut current_length = 0;
+
+    for &block in disk_map {
+        match block {
+            Some(file_id) => {
+                if Some(file_id) == current_file_id {
+                    current_length += 1;
+                } else {
+                    if let Some(id) = current_file_id {
+                        file_blocks.push((id, current_length));
+                    }
+                    current_file_id = Some(file_id);
+                    current_length = 1;
+                }
+            }
+            None => {
+                if let Some(id) = current_file_id {
+                    file_blocks.push((id, current_length));
+                    current_file_id = None;
+                    current_length = 0;
+                }
+            }
+        }
+    }
+    if let Some(id) = current_file_id {
+        file_blocks.push((id, current_length));
+    }
+
+    file_blocks.reverse();
+    file_blocks
+}
+
+#[allow(dead_code)]
+fn get_file_by_size(file_blocks: &[(usize, usize)], size: usize) -> Option<(usize, usize)> {
+    file_blocks
+        .iter()
+        .find(|&&(_, file_size)| file_size == size)
+        .cloned()
+}
+
+fn compact_files(mut disk_map: Vec<Option<usize>>) -> Vec<Option<usize>> {
+    let free_spans = find_free_spans(&disk_map);
+    let mut file_blocks = get_file_blocks(&disk_map);
+
+    for (mut free_start, mut free_length) in free_spans {
+        // Sort file_blocks by file_id in descending order
+        file_blocks.sort_by_key(|&(file_id, _)| std::cmp::Reverse(file_id));
+
+        while free_length > 0 {
+            if let Some((file_id, file_size)) = file_blocks
+                .iter_mut()
+                .filter(|&&mut (_, size)| size <= free_length && size > 0)
+                .max_by_key(|&&mut (id, _)| id)
+                .cloned()
+            {
+                disk_map[free_start..free_start + file_size].fill(Some(file_id));
+
+                // Set the moved file blocks to None in the original positions
+                let mut remaining = file_size;
+                for read_pos in (0..disk_map.len()).rev() {
+                    if disk_map[read_pos] == Some(file_id) {
+                        disk_map[read_pos] = None;
+                        remaining -= 1;
+                        if remaining == 0 {
+                            break;
+                        }
+                    }
+                }
+
+                // Remove the file from file_blocks
+                let file_pos = file_blocks
+                    .iter()
+                    .position(|&(id, _)| id == file_id)
+                    .unwrap();
+                file_blocks.remove(file_pos);
+
+                // Update the free_length and free_start for the next iteration
+                free_length -= file_size;
+                free_start += file_size;
+            } else {
+                // No file fits in the remaining free space
+                break;
+            }
+        }
+    }
+    disk_map
+}
+
+fn calculate_checksum(disk_map: &[Option<usize>]) -> usize {
+    disk_map
+        .iter()
+        .enumerate()
+        .filter_map(|(pos, &file_id)| file_id.map(|id| pos * id))
+        .sum()
+}
+
+/// The disk as in the puzzle text; ids past 9 are drawn as `#`.
+fn disk_map_string(disk_map: &[Option<usize>]) -> String {
+    disk_map
+        .iter()
+        .map(|&block| match block {
+            Some(id) => std::char::from_digit(id as u32, 10).unwrap_or('#'),
+            None => '.',
+        })
+        .collect()
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(input: &str) -> Result<()> {
+    parse_disk_map(input)?;
+    Ok(())
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    let disk_map = timed_parse(|| parse_disk_map(input))?;
+    trace!(disk = %disk_map_string(&disk_map), "before compacting");
+    let compacted_map = compact_blocks(disk_map);
+    trace!(disk = %disk_map_string(&compacted_map), "after compacting");
+    let checksum = calculate_checksum(&compacted_map);
+    Ok(checksum.into())
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    let disk_map = timed_parse(|| parse_disk_map(input))?;
+    trace!(disk = %disk_map_string(&disk_map), "before compacting");
+    let compacted_map = compact_files(disk_map);
+    trace!(disk = %disk_map_string(&compacted_map), "after compacting");
+    let checksum = calculate_checksum(&compacted_map);
+    Ok(checksum.into())
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        let input = "2333133121414131402";
+        let disk_map = parse_disk_map(input).unwrap();
+        trace!(disk = %disk_map_string(&disk_map), "before compacting");
+    let compacted_map = compact_files(disk_map);
+    trace!(disk = %disk_map_string(&compacted_map), "after compacting");
+        let checksum = calculate_checksum(&compacted_map);
+        assert_eq!(checksum, 2858);
+    }
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(1928));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(2858));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 101);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
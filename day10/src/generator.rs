@@ -0,0 +1,71 @@
+use common::generator::{GenOptions, Generated};
+use std::collections::HashSet;
+
+/*
+A `size` x `size` map (50 by default) of random heights with hiking trails from 0 to 9 walked
+into it. The answers come from pushing the reachable summits and the trail counts down from the
+summits, one height at a time.
+*/
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let n = options.size_or(50).max(2);
+    let mut heights: Vec<Vec<u8>> = (0..n)
+        .map(|_| (0..n).map(|_| rng.u8(0..=9)).collect())
+        .collect();
+
+    for _ in 0..n * n / 25 {
+        let (mut x, mut y) = (rng.usize(..n), rng.usize(..n));
+        for height in 0..=9 {
+            heights[y][x] = height;
+            let (dx, dy) = [(1, 0), (0, 1), (-1, 0), (0, -1)][rng.usize(..4)];
+            x = x.saturating_add_signed(dx).min(n - 1);
+            y = y.saturating_add_signed(dy).min(n - 1);
+        }
+    }
+
+    let neighbours = |x: usize, y: usize| {
+        [(1, 0), (0, 1), (-1, 0), (0, -1)].into_iter().filter_map(move |(dx, dy)| {
+            let (nx, ny) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            (nx < n && ny < n).then_some((nx, ny))
+        })
+    };
+    let mut summits: Vec<Vec<HashSet<(usize, usize)>>> = vec![vec![HashSet::new(); n]; n];
+    let mut trails = vec![vec![0u64; n]; n];
+    for height in (0..=9).rev() {
+        for y in 0..n {
+            for x in 0..n {
+                if heights[y][x] != height {
+                    continue;
+                }
+                if height == 9 {
+                    summits[y][x].insert((x, y));
+                    trails[y][x] = 1;
+                    continue;
+                }
+                for (nx, ny) in neighbours(x, y) {
+                    if heights[ny][nx] == height + 1 {
+                        let reachable = summits[ny][nx].clone();
+                        summits[y][x].extend(reachable);
+                        trails[y][x] += trails[ny][nx];
+                    }
+                }
+            }
+        }
+    }
+
+    let (mut score, mut rating) = (0u64, 0u64);
+    for y in 0..n {
+        for x in 0..n {
+            if heights[y][x] == 0 {
+                score += summits[y][x].len() as u64;
+                rating += trails[y][x];
+            }
+        }
+    }
+    let input: String = heights
+        .iter()
+        .map(|row| row.iter().map(|h| char::from(b'0' + h)).collect::<String>() + "\n")
+        .collect();
+    Generated::unknown(input).part1(score).part2(rating)
+}
@@ -0,0 +1,94 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+use grid::{Grid, Pos};
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/10
+*/
+
+fn parse_input(contents: &str) -> ParseResult<Grid<u32>> {
+    let input = Input::new(10, contents);
+    let lines = input.grid_lines()?;
+    Grid::try_from_lines(&lines, |pos, c| {
+        c.to_digit(10).ok_or_else(|| {
+            let line = lines[pos.y as usize];
+            let at = line.char_indices().nth(pos.x as usize).map_or(0, |(i, _)| i);
+            input.error(&line[at..at + c.len_utf8()], "expected a height from 0 to 9")
+        })
+    })
+}
+
+/// The neighbours one step higher than `position`.
+fn uphill(grid: &Grid<u32>, position: Pos) -> impl Iterator<Item = Pos> + '_ {
+    grid.neighbours4(position)
+        .filter(move |&next| grid[next] == grid[position] + 1)
+}
+
+fn calculate_trailhead_scores(grid: &Grid<u32>) -> usize {
+    grid.find_all(&0)
+        .map(|trailhead| {
+            search::dfs(trailhead, |&p| uphill(grid, p))
+                .into_iter()
+                .filter(|&p| grid[p] == 9)
+                .count()
+        })
+        .sum()
+}
+
+/// Every uphill trail from a trailhead has as many steps as it climbs, so all trails to a
+/// summit are shortest paths and the rating is their count.
+fn calculate_trailhead_ratings(grid: &Grid<u32>) -> u64 {
+    grid.find_all(&0)
+        .map(|trailhead| {
+            let trails = search::bfs_all(trailhead, |&p| uphill(grid, p));
+            grid.find_all(&9).map(|summit| trails.count_paths(&summit)).sum::<u64>()
+        })
+        .sum()
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_input(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let grid = timed_parse(|| parse_input(contents))?;
+
+    let total_score = calculate_trailhead_scores(&grid);
+    Ok(total_score.into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let grid = timed_parse(|| parse_input(contents))?;
+
+    let total_score = calculate_trailhead_ratings(&grid);
+    Ok(total_score.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(36));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(81));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 20);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
@@ -0,0 +1,55 @@
+use common::generator::{GenOptions, Generated};
+use std::collections::HashMap;
+
+/*
+A line of `size` stones (8 by default) with up to seven digits each, like the real input. The
+answers blink a map from engraved number to how many stones carry it.
+*/
+
+fn blink(stones: &HashMap<u64, u64>) -> HashMap<u64, u64> {
+    let mut next = HashMap::new();
+    for (&stone, &count) in stones {
+        let digits = stone.checked_ilog10().unwrap_or(0) + 1;
+        let split = 10u64.pow(digits / 2);
+        let engraved: &[u64] = match stone {
+            0 => &[1],
+            _ if digits % 2 == 0 => &[stone / split, stone % split],
+            _ => &[stone * 2024],
+        };
+        for &stone in engraved {
+            *next.entry(stone).or_default() += count;
+        }
+    }
+    next
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let stones: Vec<u64> = (0..options.size_or(8).max(1))
+        .map(|_| match rng.u8(..8) {
+            0 => 0,
+            _ => {
+                let digits = rng.u32(1..=7);
+                rng.u64(..10u64.pow(digits))
+            }
+        })
+        .collect();
+
+    let mut counts: HashMap<u64, u64> = HashMap::new();
+    for &stone in &stones {
+        *counts.entry(stone).or_default() += 1;
+    }
+    let mut after = [0u64; 2];
+    for blinks in 1..=75 {
+        counts = blink(&counts);
+        match blinks {
+            25 => after[0] = counts.values().sum(),
+            75 => after[1] = counts.values().sum(),
+            _ => {}
+        }
+    }
+
+    let stones: Vec<String> = stones.iter().map(u64::to_string).collect();
+    let [part1, part2] = after;
+    Generated::unknown(stones.join(" ") + "\n").part1(part1).part2(part2)
+}
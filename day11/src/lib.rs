@@ -0,0 +1,132 @@
+use anyhow::{anyhow, Result};
+use common::{timed_parse, Answer, Input, ParseResult};
+use std::collections::HashMap;
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/11
+*/
+
+/// The stone engraved in place of `stone`, unless it no longer fits.
+fn times_2024(stone: u64) -> Result<u64> {
+    stone.checked_mul(2024).ok_or_else(|| anyhow!("Stone {} is too large to multiply by 2024", stone))
+}
+
+fn blink(stones: Vec<u64>) -> Result<Vec<u64>> {
+    let mut new_stones = Vec::new();
+    for stone in stones {
+        if stone == 0 {
+            new_stones.push(1);
+        } else if stone.to_string().len() % 2 == 0 {
+            let digits = stone.to_string();
+            let mid = digits.len() / 2;
+            let left = digits[..mid].parse::<u64>().unwrap();
+            let right = digits[mid..].parse::<u64>().unwrap();
+            new_stones.push(left);
+            new_stones.push(right);
+        } else {
+            new_stones.push(times_2024(stone)?);
+        }
+    }
+    Ok(new_stones)
+}
+
+/*
+* In the end it was way easier than expected.
+* As the stones dont interact with each other, we can just simulate the process for each stone.
+* This saves my PC and my sanity.
+* */
+
+fn count_blink_stones(s: u64, blinks_left: u32, cache: &mut HashMap<(u64, u32), u64>) -> Result<u64> {
+    if blinks_left == 0 {
+        return Ok(1);
+    }
+
+    if let Some(&cached_result) = cache.get(&(s, blinks_left)) {
+        return Ok(cached_result);
+    }
+
+    let result = if s == 0 {
+        count_blink_stones(1, blinks_left - 1, cache)?
+    } else {
+        let digits = s.to_string();
+        if digits.len().is_multiple_of(2) {
+            let mid = digits.len() / 2;
+            let left: u64 = digits[..mid].parse().unwrap();
+            let right: u64 = digits[mid..].parse().unwrap();
+            count_blink_stones(left, blinks_left - 1, cache)?
+                + count_blink_stones(right, blinks_left - 1, cache)?
+        } else {
+            count_blink_stones(times_2024(s)?, blinks_left - 1, cache)?
+        }
+    };
+
+    cache.insert((s, blinks_left), result);
+    Ok(result)
+}
+
+fn parse_stones(contents: &str) -> ParseResult<Vec<u64>> {
+    let input = Input::new(11, contents);
+    contents
+        .split_whitespace()
+        .map(|s| input.parse(s))
+        .collect()
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_stones(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let mut stones = timed_parse(|| parse_stones(contents))?;
+    let blinks: u32 = 25;
+    for _ in 0..blinks {
+        stones = blink(stones)?;
+    }
+    Ok(stones.len().into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let stones = timed_parse(|| parse_stones(contents))?;
+    let mut cache: HashMap<(u64, u32), u64> = HashMap::new();
+
+    let answer: u64 = stones
+        .iter()
+        .map(|&s| count_blink_stones(s, 75, &mut cache))
+        .sum::<Result<_>>()?;
+    Ok(answer.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(55312));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(65601038650482));
+    }
+
+    #[test]
+    fn test_rejects_overflowing_stone() {
+        let err = part2("1000000000000000000\n").unwrap_err();
+        assert_eq!(err.to_string(), "Stone 1000000000000000000 is too large to multiply by 2024");
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 3);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
@@ -0,0 +1,66 @@
+use common::generator::{render, GenOptions, Generated};
+
+/*
+A `size` x `size` garden (140 by default). Each plot mostly copies the plant of its left or upper
+neighbour, which grows irregular regions with holes and enclaves. The answers flood-fill the
+regions, count fence segments per plot side and count sides as region corners.
+*/
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let n = options.size_or(140).max(1);
+
+    let mut rows = vec![vec![0u8; n]; n];
+    for y in 0..n {
+        for x in 0..n {
+            rows[y][x] = match rng.u8(..20) {
+                0..=8 if x > 0 => rows[y][x - 1],
+                9..=17 if y > 0 => rows[y - 1][x],
+                _ => rng.u8(b'A'..=b'Z'),
+            };
+        }
+    }
+
+    let plant = |x: isize, y: isize| {
+        let inside = (0..n as isize).contains(&x) && (0..n as isize).contains(&y);
+        inside.then(|| rows[y as usize][x as usize])
+    };
+    let mut region = vec![vec![usize::MAX; n]; n];
+    let mut totals: Vec<(u64, u64, u64)> = Vec::new();
+    for start_y in 0..n {
+        for start_x in 0..n {
+            if region[start_y][start_x] != usize::MAX {
+                continue;
+            }
+            let id = totals.len();
+            let (mut area, mut perimeter, mut corners) = (0, 0, 0);
+            let mut stack = vec![(start_x as isize, start_y as isize)];
+            region[start_y][start_x] = id;
+            while let Some((x, y)) = stack.pop() {
+                let here = plant(x, y);
+                let same = |dx: isize, dy: isize| plant(x + dx, y + dy) == here;
+                area += 1;
+                for (dx, dy) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+                    if !same(dx, dy) {
+                        perimeter += 1;
+                    } else if region[(y + dy) as usize][(x + dx) as usize] == usize::MAX {
+                        region[(y + dy) as usize][(x + dx) as usize] = id;
+                        stack.push((x + dx, y + dy));
+                    }
+                }
+                for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+                    let (side, other) = (same(dx, 0), same(0, dy));
+                    if !side && !other || side && other && !same(dx, dy) {
+                        corners += 1;
+                    }
+                }
+            }
+            totals.push((area, perimeter, corners));
+        }
+    }
+
+    let fence: u64 = totals.iter().map(|(area, perimeter, _)| area * perimeter).sum();
+    let discounted: u64 = totals.iter().map(|(area, _, corners)| area * corners).sum();
+    let input = render(&rows);
+    Generated::unknown(input).part1(fence).part2(discounted)
+}
@@ -0,0 +1,146 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+use grid::{Dir, Grid, Pos};
+use std::collections::{HashMap, HashSet};
+use tracing::debug;
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/12
+*/
+
+/// The cells of the region of `start`, in flood-fill order.
+fn region(map: &Grid<char>, start: Pos) -> Vec<Pos> {
+    let plant = map[start];
+    search::dfs(start, |&pos| {
+        map.neighbours4(pos).filter(move |&next| map[next] == plant)
+    })
+}
+
+fn calculate_fence_price_by_perimeter(map: &Grid<char>) -> i32 {
+    let mut visited = HashSet::new();
+    let mut total_price = 0;
+
+    for pos in map.positions() {
+        if !visited.contains(&pos) {
+            let cells = region(map, pos);
+            let area = cells.len() as i32;
+            let perimeter = cells
+                .iter()
+                .flat_map(|cell| cell.neighbours4())
+                .filter(|&next| map.get(next) != Some(&map[pos]))
+                .count() as i32;
+            let price = area * perimeter;
+            debug!(
+                "Region of {} plants with area {} and perimeter {} has price {}",
+                map[pos], area, perimeter, price
+            );
+            total_price += price;
+            visited.extend(cells);
+        }
+    }
+    total_price
+}
+
+fn calculate_fence_price_by_sides(map: &Grid<char>) -> i32 {
+    let mut visited_cells = HashSet::new();
+    let mut total_price = 0;
+
+    for pos in map.positions() {
+        if !visited_cells.contains(&pos) {
+            let cells = region(map, pos);
+            let area = cells.len() as i32;
+            let sides = count_sides(map, &cells);
+            let price = area * sides;
+            debug!(
+                "Region of {} plants with area {} and sides {} has price {}",
+                map[pos], area, sides, price
+            );
+            total_price += price;
+            visited_cells.extend(cells);
+        }
+    }
+    total_price
+}
+
+/// Cells with a fence on the same side that touch each other form one side of the region.
+fn count_sides(map: &Grid<char>, cells: &[Pos]) -> i32 {
+    let plant = map[cells[0]];
+    let mut perimeter_map: HashMap<Dir, HashSet<Pos>> = HashMap::new();
+    for &cell in cells {
+        for dir in Dir::ALL {
+            if map.get(cell.step(dir)) != Some(&plant) {
+                perimeter_map.entry(dir).or_default().insert(cell);
+            }
+        }
+    }
+
+    let mut sides = 0;
+    for perimeter_cells in perimeter_map.values() {
+        let mut seen_perimeter_cells = HashSet::new();
+        for &perimeter_cell in perimeter_cells {
+            if !seen_perimeter_cells.contains(&perimeter_cell) {
+                sides += 1;
+                seen_perimeter_cells.extend(search::bfs_reach(perimeter_cell, |&current| {
+                    current
+                        .neighbours4()
+                        .filter(|neighbor| perimeter_cells.contains(neighbor))
+                }));
+            }
+        }
+    }
+
+    sides
+}
+
+fn parse_map(contents: &str) -> ParseResult<Grid<char>> {
+    let input = Input::new(12, contents);
+    let lines = input.grid_lines()?;
+    Ok(Grid::from_lines(&lines, |c| c))
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_map(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let map = timed_parse(|| parse_map(contents))?;
+
+    let total_price = calculate_fence_price_by_perimeter(&map);
+    total_price.try_into()
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let map = timed_parse(|| parse_map(contents))?;
+
+    let total_price = calculate_fence_price_by_sides(&map);
+    total_price.try_into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(1930));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(1206));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 15);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
@@ -0,0 +1,82 @@
+use common::generator::{GenOptions, Generated, Rng};
+
+/*
+`size` claw machines (320 by default) with buttons moving 10 to 99 along each axis. A third have a
+prize reachable in at most 100 presses of each button, a third have a prize that is only
+reachable once it is moved by 10000000000000, and the rest have a prize nudged off a reachable
+one. The answers apply Cramer's rule to each machine, requiring whole, non-negative presses.
+*/
+
+const OFFSET: i128 = 10_000_000_000_000;
+
+type Machine = [(i128, i128); 3];
+
+/// Fewest tokens to win, if the prize can be reached at all.
+fn tokens([(ax, ay), (bx, by), (px, py)]: Machine, max_presses: Option<i128>) -> Option<i128> {
+    let det = ax * by - ay * bx;
+    if det == 0 {
+        return None;
+    }
+    let (a, b) = (px * by - py * bx, ax * py - ay * px);
+    if a % det != 0 || b % det != 0 {
+        return None;
+    }
+    let (a, b) = (a / det, b / det);
+    let allowed = 0..=max_presses.unwrap_or(i128::MAX);
+    (allowed.contains(&a) && allowed.contains(&b)).then_some(3 * a + b)
+}
+
+fn buttons(rng: &mut Rng) -> [(i128, i128); 2] {
+    loop {
+        let a = (rng.i128(10..100), rng.i128(10..100));
+        let b = (rng.i128(10..100), rng.i128(10..100));
+        if a.0 * b.1 != a.1 * b.0 {
+            return [a, b];
+        }
+    }
+}
+
+/// A prize near the origin that is reached exactly after moving it by `OFFSET`.
+fn far_prize(rng: &mut Rng, [(ax, ay), (bx, by)]: [(i128, i128); 2]) -> Option<(i128, i128)> {
+    let det = (ax * by - ay * bx) as f64;
+    let a = (OFFSET * by - OFFSET * bx) as f64 / det;
+    let b = (ax * OFFSET - ay * OFFSET) as f64 / det;
+    if a < 0.0 || b < 0.0 {
+        return None;
+    }
+    for _ in 0..1000 {
+        let a = a as i128 + rng.i128(-200..200);
+        let b = b as i128 + rng.i128(-200..200);
+        let prize = (a * ax + b * bx - OFFSET, a * ay + b * by - OFFSET);
+        if (0..20_000).contains(&prize.0) && (0..20_000).contains(&prize.1) {
+            return Some(prize);
+        }
+    }
+    None
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let (mut near, mut far) = (0i128, 0i128);
+
+    let mut machines = Vec::new();
+    for _ in 0..options.size_or(320) {
+        let [a, b] = buttons(&mut rng);
+        let (presses_a, presses_b) = (rng.i128(0..=100), rng.i128(0..=100));
+        let reachable = (presses_a * a.0 + presses_b * b.0, presses_a * a.1 + presses_b * b.1);
+        let prize = match rng.u8(..3) {
+            0 => reachable,
+            1 => far_prize(&mut rng, [a, b]).unwrap_or(reachable),
+            _ => (reachable.0 + rng.i128(1..4), reachable.1),
+        };
+
+        near += tokens([a, b, prize], Some(100)).unwrap_or(0);
+        far += tokens([a, b, (prize.0 + OFFSET, prize.1 + OFFSET)], None).unwrap_or(0);
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+
+    Generated::unknown(machines.join("\n")).part1(near as u64).part2(far as u64)
+}
@@ -0,0 +1,143 @@
+use anyhow::{anyhow, Result};
+use common::{timed_parse, Answer, Input, ParseResult};
+use tracing::debug;
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/13
+*/
+
+type Machine = ((i128, i128), (i128, i128), (i128, i128));
+
+fn parse_input(data: &str) -> ParseResult<Vec<Machine>> {
+    let input = Input::new(13, data);
+    input
+        .blocks()
+        .into_iter()
+        .map(|group| {
+            let mut lines = group.lines();
+            let mut next_line = |prefix: &str| {
+                let line = lines
+                    .next()
+                    .ok_or_else(|| input.error(group, format!("expected a `{}` line", prefix)))?;
+                Ok((line, extract_coordinates(&input, line, prefix)?))
+            };
+            let (_, a @ (ax, ay)) = next_line("Button A: ")?;
+            let (line, b @ (bx, by)) = next_line("Button B: ")?;
+            // Cramer's rule needs the buttons to move in different directions.
+            if ax * by == ay * bx {
+                return Err(input.error(line, "buttons A and B move in parallel"));
+            }
+            Ok((a, b, next_line("Prize: ")?.1))
+        })
+        .collect()
+}
+
+fn extract_coordinates(input: &Input, line: &str, prefix: &str) -> ParseResult<(i128, i128)> {
+    let coords = line
+        .strip_prefix(prefix)
+        .ok_or_else(|| input.error(line, format!("expected a line starting with `{}`", prefix)))?;
+    let (x, y) = coords
+        .split_once(", ")
+        .ok_or_else(|| input.error(coords, "expected `X.., Y..`"))?;
+    let coordinate = |value: &str, axis: char| {
+        value
+            .strip_prefix(axis)
+            .and_then(|v| v.strip_prefix('+').or_else(|| v.strip_prefix('=')))
+            .ok_or_else(|| input.error(value, format!("expected `{}+N` or `{}=N`", axis, axis)))
+            .and_then(|v| input.parse::<u32>(v))
+            .map(i128::from)
+    };
+    Ok((coordinate(x, 'X')?, coordinate(y, 'Y')?))
+}
+
+/*
+*
+* Diophantine equation: https://en.wikipedia.org/wiki/Diophantine_equation
+* Cramer's rule: https://en.wikipedia.org/wiki/Cramer%27s_rule
+*
+*/
+fn calculate_total(machines: &[Machine], offset: i128, max_presses: Option<i128>) -> Result<Answer> {
+    let mut total = 0;
+    let mut prizes_won = 0;
+
+    for &((ax, ay), (bx, by), (px, py)) in machines {
+        let px = px + offset;
+        let py = py + offset;
+
+        // Cramer's rule; the parser rejects parallel buttons, so the determinant isn't 0
+        let denominator = ax * by - ay * bx; // determinant
+        let m = (px * by - py * bx) / denominator;
+        let n = (ax * py - ay * px) / denominator;
+
+        if m * denominator != (px * by - py * bx) || n * denominator != (ax * py - ay * px) {
+            continue;
+        }
+
+        // Buttons can't be pressed a negative number of times, nor more than the limit
+        let allowed = 0..=max_presses.unwrap_or(i128::MAX);
+        if !allowed.contains(&m) || !allowed.contains(&n) {
+            continue;
+        }
+
+        // Diophantine equation with the solutions by Cramer's rule
+        total += 3 * m + n;
+        prizes_won += 1;
+    }
+
+    debug!("Prizes won: {}", prizes_won);
+    u64::try_from(total).map(Answer::from).map_err(|_| anyhow!("{} tokens don't fit in 64 bits", total))
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_input(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let machines = timed_parse(|| parse_input(contents))?;
+    calculate_total(&machines, 0, Some(100))
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let machines = timed_parse(|| parse_input(contents))?;
+    calculate_total(&machines, 10_i128.pow(13), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(480));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(875318608908));
+    }
+
+    #[test]
+    fn test_buttons() {
+        // Button B doesn't move along Y.
+        let flat = "Button A: X+2, Y+3\nButton B: X+1, Y+0\nPrize: X=2, Y=2\n";
+        assert_eq!(part2(flat).unwrap(), Answer::Number(13333333333336));
+        assert_eq!(part1(&flat.replace("X=2, Y=2", "X=5, Y=6")).unwrap(), Answer::Number(7));
+        let parallel = "Button A: X+2, Y+4\nButton B: X+1, Y+2\nPrize: X=5, Y=10\n";
+        let err = part2(parallel).unwrap_err().to_string();
+        assert!(err.starts_with("day 13, line 2, column 1: buttons A and B move in parallel"));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 30);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
@@ -0,0 +1,68 @@
+use crate::{HEIGHT, WIDTH};
+use common::generator::{GenOptions, Generated};
+use std::collections::HashSet;
+
+/*
+`size` robots (500 by default) that, at a random second, stand on distinct tiles drawing a
+Christmas tree, the rest scattered around it. Each robot is given a random velocity and walked
+back from there. The answers simulate the robots second by second: the safety factor after 100
+seconds, and the first second at which no two robots share a tile.
+*/
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let robots = options.size_or(500).clamp(1, WIDTH * HEIGHT);
+    let (width, height) = (WIDTH as i64, HEIGHT as i64);
+
+    let mut tiles: Vec<(i64, i64)> = Vec::new();
+    let (top_x, top_y) = (rng.i64(20..width - 20), rng.i64(0..height - 40));
+    'tree: for row in 0..30 {
+        for dx in -row..=row {
+            if tiles.len() == robots * 2 / 3 {
+                break 'tree;
+            }
+            tiles.push((top_x + dx, top_y + row));
+        }
+    }
+    let mut taken: HashSet<(i64, i64)> = tiles.iter().copied().collect();
+    while tiles.len() < robots {
+        let tile = (rng.i64(0..width), rng.i64(0..height));
+        if taken.insert(tile) {
+            tiles.push(tile);
+        }
+    }
+
+    let tree_second = rng.i64(1..width * height);
+    let robots: Vec<[i64; 4]> = tiles
+        .iter()
+        .map(|&(x, y)| {
+            let (vx, vy) = (rng.i64(-width / 2..=width / 2), rng.i64(-height / 2..=height / 2));
+            let x = (x - vx * tree_second).rem_euclid(width);
+            let y = (y - vy * tree_second).rem_euclid(height);
+            [x, y, vx, vy]
+        })
+        .collect();
+
+    let at = |second: i64| {
+        robots.iter().map(move |&[x, y, vx, vy]| {
+            ((x + vx * second).rem_euclid(width), (y + vy * second).rem_euclid(height))
+        })
+    };
+    let mut quadrants = [0u64; 4];
+    for (x, y) in at(100) {
+        if x != width / 2 && y != height / 2 {
+            quadrants[usize::from(x > width / 2) + 2 * usize::from(y > height / 2)] += 1;
+        }
+    }
+    let distinct = (1..=tree_second)
+        .find(|&second| at(second).collect::<HashSet<_>>().len() == robots.len())
+        .expect("the tree second has every robot on its own tile");
+
+    let input: String = robots
+        .iter()
+        .map(|[x, y, vx, vy]| format!("p={},{} v={},{}\n", x, y, vx, vy))
+        .collect();
+    Generated::unknown(input)
+        .part1(quadrants.iter().product::<u64>())
+        .part2(distinct as u64)
+}
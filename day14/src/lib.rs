@@ -0,0 +1,197 @@
+use anyhow::{bail, Result};
+use common::{timed_parse, Answer, Input, ParseResult};
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use tracing::{debug, trace};
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/14
+*/
+
+const WIDTH: usize = 101;
+const HEIGHT: usize = 103;
+
+struct Robot {
+    x: isize,
+    y: isize,
+    vx: isize,
+    vy: isize,
+}
+
+impl Robot {
+    fn update_position(&mut self, width: usize, height: usize) {
+        self.x = (self.x + self.vx).rem_euclid(width as isize);
+        self.y = (self.y + self.vy).rem_euclid(height as isize);
+    }
+}
+
+/// `X,Y` after `prefix`, each within its range.
+fn parse_vector<'a>(
+    input: &Input<'a>,
+    part: &'a str,
+    prefix: &str,
+    ranges: [RangeInclusive<isize>; 2],
+) -> ParseResult<(isize, isize)> {
+    let (x, y) = part
+        .strip_prefix(prefix)
+        .and_then(|coords| coords.split_once(','))
+        .ok_or_else(|| input.error(part, format!("expected `{}X,Y`", prefix)))?;
+    let [x, y] = [(x, &ranges[0]), (y, &ranges[1])].map(|(text, range)| {
+        let value = input.parse(text)?;
+        if !range.contains(&value) {
+            let message = format!("expected a value between {} and {}", range.start(), range.end());
+            return Err(input.error(text, message));
+        }
+        Ok(value)
+    });
+    Ok((x?, y?))
+}
+
+fn parse_input(contents: &str) -> ParseResult<Vec<Robot>> {
+    let input = Input::new(14, contents);
+    let mut robots = Vec::new();
+
+    for line in contents.lines().filter(|line| !line.is_empty()) {
+        let Some((position, velocity)) = line.split_once(' ') else {
+            return Err(input.error(line, "expected `p=X,Y v=X,Y`"));
+        };
+        let (width, height) = (WIDTH as isize, HEIGHT as isize);
+        let (x, y) = parse_vector(&input, position, "p=", [0..=width - 1, 0..=height - 1])?;
+        // Faster robots wrap around to one of these, and keep the positions far from overflowing.
+        let speeds = [1 - width..=width - 1, 1 - height..=height - 1];
+        let (vx, vy) = parse_vector(&input, velocity, "v=", speeds)?;
+
+        robots.push(Robot { x, y, vx, vy });
+    }
+
+    Ok(robots)
+}
+
+/// The robots after `seconds`, drawn as the puzzle text draws them.
+fn grid_picture(robots: &[Robot], seconds: usize) -> String {
+    let mut grid = vec![vec!['.'; WIDTH]; HEIGHT];
+
+    for robot in robots {
+        let x = (robot.x + robot.vx * seconds as isize).rem_euclid(WIDTH as isize);
+        let y = (robot.y + robot.vy * seconds as isize).rem_euclid(HEIGHT as isize);
+        grid[y as usize][x as usize] = '#';
+    }
+
+    grid.iter().map(|row| row.iter().collect::<String>() + "\n").collect()
+}
+
+fn calculate_multiplier(robots: &Vec<Robot>) -> Result<usize> {
+    let mut final_positions = HashSet::new();
+    let mut multiplier = 0;
+
+    while final_positions.len() != robots.len() {
+        // Every robot is back where it started after this many seconds, so the rest repeats.
+        if multiplier == WIDTH * HEIGHT {
+            bail!("Robots never all stand on separate tiles");
+        }
+        final_positions.clear();
+        multiplier += 1;
+
+        for robot in robots {
+            let final_x = (robot.x + robot.vx * multiplier as isize).rem_euclid(WIDTH as isize);
+            let final_y = (robot.y + robot.vy * multiplier as isize).rem_euclid(HEIGHT as isize);
+            final_positions.insert((final_x, final_y));
+        }
+        trace!(seconds = multiplier, overlapping = robots.len() - final_positions.len());
+    }
+
+    debug!(seconds = multiplier, "robots form the tree:\n{}", grid_picture(robots, multiplier));
+    Ok(multiplier)
+}
+
+// The example robots move on an 11x7 grid instead of the real 101x103 one
+fn safety_factor(mut robots: Vec<Robot>, width: usize, height: usize, seconds: usize) -> usize {
+    for _ in 0..seconds {
+        for robot in robots.iter_mut() {
+            robot.update_position(width, height);
+        }
+    }
+
+    let mid_x = (width / 2) as isize;
+    let mid_y = (height / 2) as isize;
+    let mut quadrants = [0, 0, 0, 0];
+
+    for robot in &robots {
+        if robot.x == mid_x || robot.y == mid_y {
+            continue;
+        }
+        if robot.x < mid_x && robot.y < mid_y {
+            quadrants[0] += 1;
+        } else if robot.x >= mid_x && robot.y < mid_y {
+            quadrants[1] += 1;
+        } else if robot.x < mid_x && robot.y >= mid_y {
+            quadrants[2] += 1;
+        } else {
+            quadrants[3] += 1;
+        }
+    }
+
+    quadrants.iter().product::<usize>()
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_input(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let robots = timed_parse(|| parse_input(contents))?;
+    let safety_factor = safety_factor(robots, WIDTH, HEIGHT, 100);
+    Ok(safety_factor.into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let robots = timed_parse(|| parse_input(contents))?;
+
+    let multiplier = calculate_multiplier(&robots)?;
+
+    Ok(multiplier.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        let robots = parse_input(EXAMPLE).unwrap();
+        assert_eq!(safety_factor(robots, 11, 7, 100), 12);
+    }
+
+    #[test]
+    fn test_rejects_malformed_robot() {
+        let err = part1("p=0,4 v=3,-3\np=6,3 v=-1\n").unwrap_err();
+        assert!(err.to_string().starts_with("day 14, line 2, column 7: expected `v=X,Y`"));
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_robot() {
+        let err = part1("p=0,4 v=3,-3\np=6,3 v=-1,-103\n").unwrap_err();
+        let message = "day 14, line 2, column 12: expected a value between -102 and 102";
+        assert!(err.to_string().starts_with(message));
+    }
+
+    #[test]
+    fn test_robots_that_never_separate() {
+        let err = part2("p=0,4 v=3,-3\np=0,4 v=3,-3\n").unwrap_err();
+        assert_eq!(err.to_string(), "Robots never all stand on separate tiles");
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 200);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
@@ -0,0 +1,77 @@
+use common::generator::{render, GenOptions, Generated};
+
+/*
+A `size` x `size` warehouse (50 by default) walled all around, with scattered walls, boxes on
+about a fifth of the floor and the robot on a free tile, followed by 8 * size * size random moves.
+Part 1 is checked by pushing boxes on the narrow map; the wide map of part 2 is left to the
+solver.
+*/
+
+const ARROWS: &[u8] = b"^v<>";
+
+fn narrow_gps(mut rows: Vec<Vec<u8>>, moves: &[u8]) -> u64 {
+    let mut y = rows.iter().position(|row| row.contains(&b'@')).unwrap_or(0);
+    let mut x = rows[y].iter().position(|&c| c == b'@').unwrap_or(0);
+    for &arrow in moves {
+        let (dx, dy): (isize, isize) = match arrow {
+            b'^' => (0, -1),
+            b'v' => (0, 1),
+            b'<' => (-1, 0),
+            _ => (1, 0),
+        };
+        let step = |x: usize, y: usize| (x.wrapping_add_signed(dx), y.wrapping_add_signed(dy));
+        let (mut end_x, mut end_y) = step(x, y);
+        while rows[end_y][end_x] == b'O' {
+            (end_x, end_y) = step(end_x, end_y);
+        }
+        if rows[end_y][end_x] == b'#' {
+            continue;
+        }
+        let (next_x, next_y) = step(x, y);
+        rows[end_y][end_x] = b'O';
+        rows[next_y][next_x] = b'@';
+        rows[y][x] = b'.';
+        (x, y) = (next_x, next_y);
+    }
+
+    let mut gps = 0;
+    for (y, row) in rows.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            if c == b'O' {
+                gps += (100 * y + x) as u64;
+            }
+        }
+    }
+    gps
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let n = options.size_or(50).max(3);
+
+    let mut rows: Vec<Vec<u8>> = (0..n)
+        .map(|y| {
+            (0..n)
+                .map(|x| match rng.u8(..20) {
+                    _ if x == 0 || y == 0 || x == n - 1 || y == n - 1 => b'#',
+                    0 => b'#',
+                    1..=4 => b'O',
+                    _ => b'.',
+                })
+                .collect()
+        })
+        .collect();
+    let (x, y) = (rng.usize(1..n - 1), rng.usize(1..n - 1));
+    rows[y][x] = b'@';
+
+    let moves: Vec<u8> = (0..8 * n * n).map(|_| ARROWS[rng.usize(..4)]).collect();
+    let mut input = render(&rows);
+    for line in moves.chunks(1000) {
+        input += "\n";
+        input += &String::from_utf8_lossy(line);
+    }
+    input.push('\n');
+
+    let gps = narrow_gps(rows, &moves);
+    Generated::unknown(input).part1(gps)
+}
@@ -0,0 +1,264 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+use grid::{Dir, Grid, Pos};
+use std::collections::HashSet;
+use tracing::debug;
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/15
+*/
+
+type Map = Grid<char>;
+
+/// The tile at `p`; the warehouse is walled in, so anything outside counts as wall.
+fn tile(m: &Map, p: Pos) -> char {
+    m.get(p).copied().unwrap_or('#')
+}
+
+fn can_step(m: &Map, p: Pos, d: Dir) -> bool {
+    let i = tile(m, p);
+    let t = p.step(d);
+    match i {
+        '.' => true,
+        '#' => false,
+        '[' => match d {
+            Dir::N | Dir::S => can_step(m, t, d) && can_step(m, t.step(Dir::E), d),
+            _ => can_step(m, t.step(d), d),
+        },
+        ']' => match d {
+            Dir::N | Dir::S => can_step(m, t, d) && can_step(m, t.step(Dir::W), d),
+            _ => can_step(m, t.step(d), d),
+        },
+        _ => can_step(m, t, d),
+    }
+}
+
+fn do_step(m: &mut Map, p: Pos, d: Dir) {
+    let i = tile(m, p);
+    let t = p.step(d);
+    match i {
+        '.' => {}
+        '#' => panic!("Trying to move walls"),
+        '[' => match d {
+            Dir::N | Dir::S => {
+                do_step(m, t, d);
+                do_step(m, t.step(Dir::E), d);
+                m[t] = '[';
+                m[t.step(Dir::E)] = ']';
+                m[p] = '.';
+                m[p.step(Dir::E)] = '.';
+            }
+            Dir::E => {
+                do_step(m, t.step(d), d);
+                m[p.step(d).step(d)] = ']';
+                m[p.step(d)] = '[';
+                m[p] = '.';
+            }
+            _ => panic!("Illegal move"),
+        },
+        ']' => match d {
+            Dir::N | Dir::S => {
+                do_step(m, t, d);
+                do_step(m, t.step(Dir::W), d);
+                m[t] = ']';
+                m[t.step(Dir::W)] = '[';
+                m[p] = '.';
+                m[p.step(Dir::W)] = '.';
+            }
+            Dir::W => {
+                do_step(m, t.step(d), d);
+                m[p.step(d).step(d)] = '[';
+                m[p.step(d)] = ']';
+                m[p] = '.';
+            }
+            _ => panic!("Illegal move"),
+        },
+        _ => {
+            do_step(m, t, d);
+            m[t] = '@';
+            m[p] = '.';
+        }
+    }
+}
+
+fn parse_input(contents: &str) -> ParseResult<(Map, Vec<Dir>)> {
+    let input = Input::new(15, contents);
+    let mut warehouse = Vec::new();
+    let mut moves = Vec::new();
+    let mut reading_moves = false;
+    let mut robots = 0;
+
+    for line in contents.lines() {
+        if line.is_empty() {
+            reading_moves = true;
+            continue;
+        }
+        if reading_moves {
+            for (pos, c) in line.char_indices() {
+                let dir = Dir::from_arrow(c).ok_or_else(|| {
+                    input.error(&line[pos..pos + c.len_utf8()], "expected a move `^`, `v`, `<` or `>`")
+                })?;
+                moves.push(dir);
+            }
+        } else {
+            // Counted in chars, like the grid's rows.
+            if let Some(width) = warehouse.first().map(|row: &&str| row.chars().count()) {
+                if line.chars().count() != width {
+                    return Err(input.error(line, format!("expected a row of width {}", width)));
+                }
+            }
+            robots += line.matches('@').count();
+            warehouse.push(line);
+        }
+    }
+
+    if robots != 1 {
+        return Err(input.eof_error(format!(
+            "expected exactly one robot `@` in the warehouse, found {}",
+            robots
+        )));
+    }
+    Ok((Grid::from_lines(&warehouse, |c| c), moves))
+}
+
+#[allow(dead_code)]
+fn print_warehouse(warehouse: &Map, robot_pos: Pos, boxes: &HashSet<Pos>) {
+    let mut picture = warehouse.clone();
+    picture[robot_pos] = '@';
+    for &pos in boxes {
+        picture[pos] = 'O';
+    }
+    debug!("{}", picture);
+}
+
+fn simulate_robot(mut warehouse: Map, moves: &[Dir]) -> i64 {
+    let mut robot_pos = Pos::default();
+    let mut boxes: HashSet<Pos> = HashSet::new();
+    for (pos, &ch) in warehouse.iter() {
+        if ch == 'O' {
+            boxes.insert(pos);
+        } else if ch == '@' {
+            robot_pos = pos;
+        }
+    }
+
+    //print_warehouse(&warehouse, robot_pos, &boxes);
+
+    for &mv in moves {
+        let new_robot_pos = robot_pos.step(mv);
+        if tile(&warehouse, new_robot_pos) == '#' {
+            continue;
+        }
+
+        if boxes.contains(&new_robot_pos) {
+            let mut current_pos = new_robot_pos;
+            let mut box_positions = Vec::new();
+
+            // Collect all consecutive boxes in the direction of the movement
+            while boxes.contains(&current_pos) {
+                box_positions.push(current_pos);
+                current_pos = current_pos.step(mv);
+            }
+
+            // Check if the last position is valid for the last box
+            if tile(&warehouse, current_pos) == '#' || boxes.contains(&current_pos) {
+                continue;
+            }
+
+            // Move all boxes in the line
+            for &pos in box_positions.iter().rev() {
+                boxes.remove(&pos);
+                boxes.insert(pos.step(mv));
+            }
+        }
+
+        // Clear the old robot position
+        warehouse[robot_pos] = '.';
+        robot_pos = new_robot_pos;
+
+        //print_warehouse(&warehouse, robot_pos, &boxes);
+    }
+
+    boxes.iter().map(|p| p.y * 100 + p.x).sum()
+}
+
+fn transform_warehouse(warehouse: &Map) -> Map {
+    let mut new_warehouse = Grid::new(warehouse.width() * 2, warehouse.height(), '.');
+    for (pos, &ch) in warehouse.iter() {
+        let tiles = match ch {
+            '#' => ['#', '#'],
+            'O' => ['[', ']'],
+            '@' => ['@', '.'],
+            _ => [ch, ch],
+        };
+        let left = Pos::new(pos.x * 2, pos.y);
+        new_warehouse[left] = tiles[0];
+        new_warehouse[left.step(Dir::E)] = tiles[1];
+    }
+    new_warehouse
+}
+
+fn gps(map: &Map, c: char) -> i64 {
+    map.find_all(&c).map(|p| p.x + 100 * p.y).sum()
+}
+
+fn simulate(mut map: Map, moves: &[Dir]) -> Result<i64> {
+    let mut robot_pos = map
+        .find(&'@')
+        .ok_or_else(|| anyhow::anyhow!("The robot `@` is missing"))?;
+
+    for &dir in moves {
+        if can_step(&map, robot_pos, dir) {
+            do_step(&mut map, robot_pos, dir);
+            robot_pos = robot_pos.step(dir);
+        }
+    }
+
+    Ok(gps(&map, '['))
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_input(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let (warehouse, moves) = timed_parse(|| parse_input(contents))?;
+    let gps_sum = simulate_robot(warehouse, &moves);
+    gps_sum.try_into()
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let (warehouse, moves) = timed_parse(|| parse_input(contents))?;
+    let transformed_warehouse = transform_warehouse(&warehouse);
+    let gps_sum = simulate(transformed_warehouse, &moves)?;
+    gps_sum.try_into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(10092));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(9021));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 10);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
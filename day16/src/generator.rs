@@ -0,0 +1,33 @@
+use common::generator::{maze_path, perfect_maze, render, GenOptions, Generated};
+
+/*
+A perfect maze of `size` x `size` tiles (141 by default, rounded up to odd) with the start in the
+bottom-left corner and the end in the top-right one. There is a single track between them, so the
+best path is that track: its cost is its steps plus 1000 per turn, starting east, and every tile
+on it counts for part 2.
+*/
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let mut rows = perfect_maze(&mut rng, options.size_or(141));
+    let n = rows.len();
+    let (start, end) = ((1, n - 2), (n - 2, 1));
+
+    let track = maze_path(&rows, start, end);
+
+    let mut heading = (1, 0);
+    let mut cost = 0u64;
+    for step in track.windows(2) {
+        let [(x0, y0), (x1, y1)] = [step[0], step[1]];
+        let direction = (x1 as isize - x0 as isize, y1 as isize - y0 as isize);
+        if direction != heading {
+            cost += 1000;
+            heading = direction;
+        }
+        cost += 1;
+    }
+
+    rows[start.1][start.0] = b'S';
+    rows[end.1][end.0] = b'E';
+    Generated::unknown(render(&rows)).part1(cost).part2(track.len())
+}
@@ -0,0 +1,122 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+use grid::{Dir, Grid, Pos};
+use search::ShortestPaths;
+use std::collections::HashSet;
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/16
+*/
+
+type Reindeer = (Pos, Dir);
+
+fn is_open(grid: &Grid<char>, position: Pos) -> bool {
+    grid.get(position).is_some_and(|&tile| tile != '#')
+}
+
+/// Moving forward costs 1 point, turning in place 1000.
+fn moves(grid: &Grid<char>, &(position, direction): &Reindeer) -> Vec<(Reindeer, usize)> {
+    let mut moves = vec![
+        ((position, direction.turn_left()), 1000),
+        ((position, direction.turn_right()), 1000),
+    ];
+    let ahead = position.step(direction);
+    if is_open(grid, ahead) {
+        moves.push(((ahead, direction), 1));
+    }
+    moves
+}
+
+/// The cheapest ways from the start, facing east, to every reachable tile and direction.
+fn cheapest_paths(grid: &Grid<char>, start: Pos) -> ShortestPaths<Reindeer, usize> {
+    search::dijkstra_all((start, Dir::E), |reindeer| moves(grid, reindeer))
+}
+
+/// The end states, in whatever direction the reindeer arrives, that are cheapest to reach.
+fn best_ends(paths: &ShortestPaths<Reindeer, usize>, end: Pos) -> Vec<(Reindeer, usize)> {
+    let arrivals: Vec<(Reindeer, usize)> = Dir::ALL
+        .iter()
+        .filter_map(|&d| Some(((end, d), paths.cost(&(end, d))?)))
+        .collect();
+    let min_cost = arrivals.iter().map(|&(_, cost)| cost).min();
+    arrivals.into_iter().filter(|&(_, cost)| Some(cost) == min_cost).collect()
+}
+
+/// The maze with its start and end tiles.
+fn parse_maze(contents: &str) -> ParseResult<(Grid<char>, Pos, Pos)> {
+    let input = Input::new(16, contents);
+    let lines = input.grid_lines()?;
+    let grid = Grid::from_lines(&lines, |c| c);
+    let [start, end] = ['S', 'E'].map(|tile| {
+        grid.find(&tile)
+            .ok_or_else(|| input.eof_error(format!("expected a `{}` tile in the maze", tile)))
+    });
+    Ok((grid, start?, end?))
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_maze(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let (grid, start, end) = timed_parse(|| parse_maze(contents))?;
+
+    let paths = cheapest_paths(&grid, start);
+    let (_, min_cost) = *best_ends(&paths, end)
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("The end tile cannot be reached"))?;
+    Ok(min_cost.into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let (grid, start, end) = timed_parse(|| parse_maze(contents))?;
+
+    let paths = cheapest_paths(&grid, start);
+    let ends: Vec<Reindeer> = best_ends(&paths, end).into_iter().map(|(state, _)| state).collect();
+    let shortest_path_tiles: HashSet<Pos> = paths
+        .nodes_on_paths(&ends)
+        .into_iter()
+        .map(|(position, _)| position)
+        .collect();
+    Ok(shortest_path_tiles.len().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(7036));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(45));
+    }
+
+    #[test]
+    fn test_example2_part1() {
+        assert_eq!(part1(EXAMPLE_2).unwrap(), Answer::Number(11048));
+    }
+
+    #[test]
+    fn test_example2_part2() {
+        assert_eq!(part2(EXAMPLE_2).unwrap(), Answer::Number(64));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 21);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
@@ -0,0 +1,82 @@
+use common::generator::{GenOptions, Generated, Rng};
+
+/*
+Programs shaped like the real ones: one output per loop, each computed from the low bits of A, and
+A shifted right by three bits per iteration. Their constants are random, and programs are drawn
+until one can output itself. That is decided by building A three bits at a time from the end of
+the program and keeping every prefix that still reproduces it; part 2 is the smallest survivor.
+Part 1 runs it on an A of `size` octal digits (16 by default).
+*/
+
+fn run(program: &[u8], a: u64) -> Vec<u8> {
+    let (mut a, mut b, mut c) = (a, 0u64, 0u64);
+    let mut output = Vec::new();
+    let mut ip = 0;
+    while ip + 1 < program.len() {
+        let operand = u64::from(program[ip + 1]);
+        let combo = match operand {
+            4 => a,
+            5 => b,
+            6 => c,
+            _ => operand,
+        };
+        match program[ip] {
+            0 => a >>= combo,
+            1 => b ^= operand,
+            2 => b = combo % 8,
+            3 if a != 0 => {
+                ip = operand as usize;
+                continue;
+            }
+            3 => {}
+            4 => b ^= c,
+            5 => output.push((combo % 8) as u8),
+            6 => b = a >> combo,
+            _ => c = a >> combo,
+        }
+        ip += 2;
+    }
+    output
+}
+
+fn draw_program(rng: &mut Rng) -> Vec<u8> {
+    let (first, second, ignored) = (rng.u8(..8), rng.u8(..8), rng.u8(..8));
+    match rng.bool() {
+        true => vec![2, 4, 1, first, 7, 5, 1, second, 4, ignored, 0, 3, 5, 5, 3, 0],
+        false => vec![2, 4, 1, first, 7, 5, 4, ignored, 1, second, 5, 5, 0, 3, 3, 0],
+    }
+}
+
+/// The smallest A for which `program` outputs itself.
+fn quine(program: &[u8]) -> Option<u64> {
+    let mut prefixes = vec![0u64];
+    for start in (0..program.len()).rev() {
+        prefixes = prefixes
+            .iter()
+            .flat_map(|prefix| (0..8).map(move |bits| prefix * 8 + bits))
+            .filter(|&a| run(program, a) == program[start..])
+            .collect();
+    }
+    prefixes.into_iter().min()
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let digits = options.size_or(16).clamp(1, 21) as u32;
+    let a = rng.u64(8u64.pow(digits - 1)..8u64.pow(digits));
+
+    loop {
+        let program = draw_program(&mut rng);
+        let Some(quine) = quine(&program) else {
+            continue;
+        };
+        let output: Vec<String> = run(&program, a).iter().map(u8::to_string).collect();
+        let program: Vec<String> = program.iter().map(u8::to_string).collect();
+        let input = format!(
+            "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            a,
+            program.join(",")
+        );
+        return Generated::unknown(input).part1(output.join(",")).part2(quine);
+    }
+}
@@ -0,0 +1,349 @@
+use anyhow::{anyhow, bail, Result};
+use common::{timed_parse, Answer, Input, ParseResult};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tracing::debug;
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/17
+*/
+
+/// Instructions a program may run before it is taken to loop forever.
+const MAX_STEPS: usize = 1 << 24;
+
+#[derive(Debug)]
+struct Registers {
+    a: u64,
+    b: u64,
+    c: u64,
+}
+
+impl Registers {
+    fn new(a: u64, b: u64, c: u64) -> Self {
+        Self { a, b, c }
+    }
+}
+
+#[derive(Debug)]
+#[allow(dead_code)]
+enum Operand {
+    Literal(u64),
+    Combo(usize),
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Adv(Operand),
+    Bxl(i32),
+    Bst(Operand),
+    Jnz(i32),
+    Bxc,
+    Out(Operand),
+    Bdv(Operand),
+    Cdv(Operand),
+}
+
+impl Instruction {
+    fn from_opcode(opcode: u8, operand: u8) -> Result<Self> {
+        Ok(match opcode {
+            0 => Instruction::Adv(Operand::Combo(operand as usize)),
+            1 => Instruction::Bxl(operand as i32),
+            2 => Instruction::Bst(Operand::Combo(operand as usize)),
+            3 => Instruction::Jnz(operand as i32),
+            4 => Instruction::Bxc,
+            5 => Instruction::Out(Operand::Combo(operand as usize)),
+            6 => Instruction::Bdv(Operand::Combo(operand as usize)),
+            7 => Instruction::Cdv(Operand::Combo(operand as usize)),
+            _ => bail!("Invalid opcode {}", opcode),
+        })
+    }
+}
+
+fn get_operand_value(operand: &Operand, registers: &Registers) -> Result<u64> {
+    Ok(match operand {
+        Operand::Literal(value) => *value,
+        Operand::Combo(value) => match value {
+            0..=3 => *value as u64,
+            4 => registers.a,
+            5 => registers.b,
+            6 => registers.c,
+            _ => bail!("Invalid combo operand {}", value),
+        },
+    })
+}
+
+/// `numerator / 2^power`; shifting every bit out leaves 0.
+fn divide(numerator: u64, power: u64) -> u64 {
+    u32::try_from(power).ok().and_then(|power| numerator.checked_shr(power)).unwrap_or(0)
+}
+
+fn run_program(registers: &mut Registers, program: &[u8]) -> Result<String> {
+    let mut output = Vec::new();
+    let mut instruction_pointer = 0;
+    let mut steps = 0;
+
+    // An opcode in the last word has no operand, so the program halts as if it ran off the end.
+    while instruction_pointer + 1 < program.len() {
+        steps += 1;
+        if steps > MAX_STEPS {
+            bail!("Program still running after {} instructions", MAX_STEPS);
+        }
+        let opcode = program[instruction_pointer];
+        let operand = program[instruction_pointer + 1];
+        let instruction = Instruction::from_opcode(opcode, operand)?;
+
+        match instruction {
+            Instruction::Adv(op) => {
+                let value = get_operand_value(&op, registers)?;
+                registers.a = divide(registers.a, value);
+            }
+            Instruction::Bxl(value) => {
+                registers.b ^= value as u64;
+            }
+            Instruction::Bst(op) => {
+                registers.b = get_operand_value(&op, registers)? % 8;
+            }
+            Instruction::Jnz(value) => {
+                if registers.a != 0 {
+                    instruction_pointer = value as usize;
+                    continue;
+                }
+            }
+            Instruction::Bxc => {
+                registers.b ^= registers.c;
+            }
+            Instruction::Out(op) => {
+                output.push((get_operand_value(&op, registers)? % 8).to_string());
+            }
+            Instruction::Bdv(op) => {
+                let value = get_operand_value(&op, registers)?;
+                registers.b = divide(registers.a, value);
+            }
+            Instruction::Cdv(op) => {
+                let value = get_operand_value(&op, registers)?;
+                registers.c = divide(registers.a, value);
+            }
+        }
+
+        instruction_pointer += 2;
+    }
+
+    Ok(output.join(","))
+}
+
+fn parse_register(input: &Input, line: Option<&str>, name: &str) -> ParseResult<u64> {
+    let prefix = format!("Register {}: ", name);
+    let line = line.ok_or_else(|| input.eof_error(format!("missing Register {}", name)))?;
+    let value = line
+        .strip_prefix(&prefix)
+        .ok_or_else(|| input.error(line, format!("expected `{}<value>`", prefix)))?;
+    input.parse(value)
+}
+
+fn parse_input(contents: &str) -> ParseResult<(Registers, Vec<u8>)> {
+    let input = Input::new(17, contents);
+    let mut lines = contents.lines().filter(|line| !line.is_empty());
+    let a = parse_register(&input, lines.next(), "A")?;
+    let b = parse_register(&input, lines.next(), "B")?;
+    let c = parse_register(&input, lines.next(), "C")?;
+    let registers = Registers::new(a, b, c);
+
+    let line = lines.next().ok_or_else(|| input.eof_error("missing Program"))?;
+    let values = line
+        .strip_prefix("Program: ")
+        .ok_or_else(|| input.error(line, "expected `Program: <opcodes>`"))?;
+
+    let mut program = Vec::new();
+    for value in values.split(',') {
+        let word: u8 = input.parse(value)?;
+        if word > 7 {
+            return Err(input.error(value, "expected a 3-bit value between 0 and 7"));
+        }
+        // Combo operand 7 is reserved, so reject it for every combo instruction.
+        if program.len() % 2 == 1 && word == 7 && matches!(program.last(), Some(0 | 2 | 5 | 6 | 7)) {
+            return Err(input.error(value, "combo operand 7 is reserved"));
+        }
+        program.push(word);
+    }
+    if program.len() % 2 == 1 {
+        return Err(input.error(line, "expected opcode and operand pairs"));
+    }
+
+    Ok((registers, program))
+}
+
+//This will probably not work in human time :(
+#[allow(dead_code)]
+fn find_initial_value(program: &[u8], register_a: u64) -> Result<u64> {
+    let mut registers = Registers::new(0, 0, 0);
+    let mut initial_value = register_a;
+
+    loop {
+        debug!("Trying out: {}", initial_value);
+        registers.a = initial_value;
+        let output = run_program(&mut registers, program)?;
+        let expected_output: String = program.iter().map(|&x| x.to_string()).collect::<Vec<_>>().join(",");
+
+        if output == expected_output {
+            return Ok(initial_value);
+        }
+
+        initial_value += 1;
+    }
+}
+
+//MORE POWER!!!!!!!!!
+#[allow(dead_code)]
+fn find_initial_value_multithreaded(program: &[u8], start_value: u64, num_threads: usize) -> u64 {
+    let program = Arc::new(program.to_vec());
+    let result = Arc::new(Mutex::new(None));
+
+    let mut handles = vec![];
+
+    for i in 0..num_threads {
+        let program = Arc::clone(&program);
+        let result = Arc::clone(&result);
+        let start = start_value + i as u64;
+
+        let handle = thread::spawn(move || {
+            let mut initial_value = start;
+            let multiplier = 8u64.pow(i as u32);
+
+            loop {
+                {
+                    let result = result.lock().unwrap();
+                    if result.is_some() {
+                        break;
+                    }
+                }
+
+                let output = run_simplified(initial_value);
+                let expected_output: String = program.iter().map(|&x| x.to_string()).collect::<Vec<_>>().join(",");
+
+                if output == expected_output {
+                    let mut result = result.lock().unwrap();
+                    *result = Some(initial_value);
+                    break;
+                }
+
+                // Increment the initial value by the multiplier
+                initial_value += multiplier;
+            }
+        });
+
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let result = result.lock().unwrap();
+    result.unwrap()
+}
+
+#[allow(dead_code)]
+fn get_out(a: u64) -> u64 {
+    let partial = (a % 8) ^ 2;
+    ((partial ^ (a >> partial)) ^ 7) % 8
+}
+
+#[allow(dead_code)]
+fn run_simplified(a: u64) -> String {
+    let mut out = Vec::new();
+    let mut a = a;
+    while a > 0 {
+        out.push(get_out(a));
+        a >>= 3;
+    }
+    out.iter().map(|&x| x.to_string()).collect::<Vec<_>>().join(",")
+}
+
+fn find_initial_value_yet_another(program: &[u8]) -> Result<u64> {
+    fn get_best_input_maybe(program: &[u8], cursor: usize, waterlevel: u64) -> Result<Option<u64>> {
+        let expected = program[cursor..].iter().map(|&x| x.to_string()).collect::<Vec<_>>().join(",");
+        for candidate in 0..8 {
+            // Past 64 bits there are no more candidates.
+            let Some(initial_value) = waterlevel.checked_mul(8).map(|value| value + candidate) else {
+                return Ok(None);
+            };
+            let mut registers = Registers::new(initial_value, 0, 0);
+            if run_program(&mut registers, program)? == expected {
+                if cursor == 0 {
+                    return Ok(Some(initial_value));
+                }
+                if let Some(ret) = get_best_input_maybe(program, cursor - 1, initial_value)? {
+                    return Ok(Some(ret));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    get_best_input_maybe(program, program.len() - 1, 0)?.ok_or_else(|| anyhow!("No valid initial value found"))
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_input(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let (mut registers, program) = timed_parse(|| parse_input(contents))?;
+    let output = run_program(&mut registers, &program)?;
+    Ok(output.into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let (_registers, program) = timed_parse(|| parse_input(contents))?;
+    let initial_value = find_initial_value_yet_another(&program);
+    initial_value.map(Answer::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::from("4,6,3,5,6,3,5,2,1,0"));
+    }
+
+    #[test]
+    fn test_example2_part2() {
+        assert_eq!(part2(EXAMPLE_2).unwrap(), Answer::Number(117440));
+    }
+
+    #[test]
+    fn test_rejects_reserved_combo_operand() {
+        let err = part1("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,7\n").unwrap_err();
+        assert!(err.to_string().starts_with("day 17, line 5, column 12: combo operand 7 is reserved"));
+    }
+
+    #[test]
+    fn test_runs_any_parsed_program() {
+        // Jumps to the last word, where the opcode has no operand.
+        let odd_jump = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,1\n";
+        assert_eq!(part1(odd_jump).unwrap(), Answer::from(""));
+        // Shifts by 64 and more leave nothing.
+        let shifts = "Register A: 7\nRegister B: 64\nRegister C: 4294967296\n\nProgram: 7,6,0,5,5,4,5,6,5,5\n";
+        assert_eq!(part1(shifts).unwrap(), Answer::from("0,0,0"));
+        // Jumps to an operand, so a 7 becomes the combo operand of `out`.
+        let reserved = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,3,1,5,7,0\n";
+        assert_eq!(part1(reserved).unwrap_err().to_string(), "Invalid combo operand 7");
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 16);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE_2);
+}
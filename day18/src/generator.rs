@@ -0,0 +1,65 @@
+use crate::{BYTES_TO_SIMULATE, GRID_SIZE};
+use common::generator::{GenOptions, Generated};
+use std::collections::VecDeque;
+
+/*
+Bytes falling on every tile of the memory space except the corners, in random order, until the
+exit is cut off. Orders that cut it off within the first kilobyte are drawn again. The list is
+padded with more bytes up to `size` lines (3450 by default). The answers run a breadth-first
+search after the first kilobyte, and bisect the list for the first byte that leaves no path.
+*/
+
+fn steps(fallen: &[(usize, usize)]) -> Option<u64> {
+    let n = GRID_SIZE;
+    let mut blocked = vec![vec![false; n]; n];
+    for &(x, y) in fallen {
+        blocked[y][x] = true;
+    }
+    let mut distance = vec![vec![None; n]; n];
+    distance[0][0] = Some(0);
+    let mut queue = VecDeque::from([(0, 0)]);
+    while let Some((x, y)) = queue.pop_front() {
+        let here = distance[y][x]?;
+        if (x, y) == (n - 1, n - 1) {
+            return Some(here);
+        }
+        for (nx, ny) in [(x + 1, y), (x.wrapping_sub(1), y), (x, y + 1), (x, y.wrapping_sub(1))] {
+            if nx < n && ny < n && !blocked[ny][nx] && distance[ny][nx].is_none() {
+                distance[ny][nx] = Some(here + 1);
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+    None
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let n = GRID_SIZE;
+    let mut bytes: Vec<(usize, usize)> = (0..n * n)
+        .map(|i| (i % n, i / n))
+        .filter(|&cell| cell != (0, 0) && cell != (n - 1, n - 1))
+        .collect();
+
+    loop {
+        rng.shuffle(&mut bytes);
+        // The exit is always cut off once every byte has fallen; find the first one that does it.
+        let (mut open, mut cut) = (0, bytes.len());
+        while cut - open > 1 {
+            let middle = (open + cut) / 2;
+            match steps(&bytes[..middle]) {
+                Some(_) => open = middle,
+                None => cut = middle,
+            }
+        }
+        if cut <= BYTES_TO_SIMULATE {
+            continue;
+        }
+
+        let kept = &bytes[..options.size_or(3450).clamp(cut, bytes.len())];
+        let input: String = kept.iter().map(|(x, y)| format!("{},{}\n", x, y)).collect();
+        let (x, y) = bytes[cut - 1];
+        let shortest = steps(&kept[..BYTES_TO_SIMULATE]).expect("the exit is open after a kilobyte");
+        return Generated::unknown(input).part1(shortest).part2(format!("{},{}", x, y));
+    }
+}
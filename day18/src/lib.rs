@@ -0,0 +1,138 @@
+use anyhow::{bail, Result};
+use common::{timed_parse, Answer, Input, ParseResult};
+use grid::{Grid, Pos};
+use tracing::debug;
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/18
+*/
+
+const GRID_SIZE: usize = 71; // The example uses a 7x7 grid and only its first 12 bytes
+const BYTES_TO_SIMULATE: usize = 1024;
+
+fn open_neighbours(grid: &Grid<char>, position: Pos) -> impl Iterator<Item = Pos> + '_ {
+    grid.neighbours4(position).filter(|&next| grid[next] == '.')
+}
+
+fn bfs(grid: &Grid<char>, start: Pos, goal: Pos) -> Option<usize> {
+    let path = search::bfs(start, |&p| open_neighbours(grid, p), |&p| p == goal)?;
+    Some(path.len() - 1)
+}
+
+fn parse_byte_positions(contents: &str, grid_size: usize) -> ParseResult<Vec<Pos>> {
+    let input = Input::new(18, contents);
+    let mut positions = Vec::new();
+    for line in contents.lines().filter(|line| !line.is_empty()) {
+        let (x, y) = line
+            .split_once(',')
+            .ok_or_else(|| input.error(line, "expected `X,Y`"))?;
+        let x: usize = input.parse(x)?;
+        let y: usize = input.parse(y)?;
+        if x >= grid_size || y >= grid_size {
+            return Err(input.error(line, format!("expected a position inside the {0}x{0} grid", grid_size)));
+        }
+        positions.push(Pos::new(x as i64, y as i64));
+    }
+    Ok(positions)
+}
+
+fn exit(grid_size: usize) -> Pos {
+    Pos::new(grid_size as i64 - 1, grid_size as i64 - 1)
+}
+
+fn shortest_path(byte_positions: &[Pos], grid_size: usize, bytes_to_simulate: usize) -> Result<usize> {
+    let mut grid = Grid::new(grid_size, grid_size, '.');
+
+    // Mark the corrupted positions
+    for &position in byte_positions.iter().take(bytes_to_simulate) {
+        grid[position] = '#';
+    }
+
+    // Print the grid for visualization
+    debug!("{}", grid);
+
+    // Find the shortest path using A*, heading for the exit
+    let goal = exit(grid_size);
+    let (_, steps) = search::astar(
+        Pos::new(0, 0),
+        |&p| open_neighbours(&grid, p).map(|next| (next, 1)),
+        |p| p.manhattan(goal),
+        |&p| p == goal,
+    )
+    .ok_or_else(|| anyhow::anyhow!("No path found"))?;
+    Ok(steps as usize)
+}
+
+fn first_blocking_byte(byte_positions: &[Pos], grid_size: usize) -> Result<Pos> {
+    let blocked = |fallen: usize| {
+        let mut grid = Grid::new(grid_size, grid_size, '.');
+        for &position in &byte_positions[..fallen] {
+            grid[position] = '#';
+        }
+        bfs(&grid, Pos::new(0, 0), exit(grid_size)).is_none()
+    };
+    if !blocked(byte_positions.len()) {
+        bail!("The exit never gets blocked");
+    }
+
+    // Once the exit is cut off it stays that way, so bisect for the byte that does it.
+    let (mut open, mut cut) = (0, byte_positions.len());
+    while cut - open > 1 {
+        let middle = (open + cut) / 2;
+        if blocked(middle) {
+            cut = middle;
+        } else {
+            open = middle;
+        }
+    }
+    Ok(byte_positions[cut - 1])
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_byte_positions(contents, GRID_SIZE)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let byte_positions = timed_parse(|| parse_byte_positions(contents, GRID_SIZE))?;
+    let steps = shortest_path(&byte_positions, GRID_SIZE, BYTES_TO_SIMULATE)?;
+    Ok(steps.into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let byte_positions = timed_parse(|| parse_byte_positions(contents, GRID_SIZE))?;
+
+    let position = first_blocking_byte(&byte_positions, GRID_SIZE)?;
+    Ok(position.to_string().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::{assert_checks, GenOptions};
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        let byte_positions = parse_byte_positions(EXAMPLE, 7).unwrap();
+        assert_eq!(shortest_path(&byte_positions, 7, 12).unwrap(), 22);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let byte_positions = parse_byte_positions(EXAMPLE, 7).unwrap();
+        assert_eq!(first_blocking_byte(&byte_positions, 7).unwrap(), Pos::new(6, 1));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 0);
+    }
+
+    // The example is drawn on a smaller grid, where part 2 never cuts off the exit.
+    common::robustness_test!(parse, [part1, part2], &generator::generate(&GenOptions::new(0, 1)).input);
+}
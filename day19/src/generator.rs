@@ -0,0 +1,67 @@
+use common::generator::{GenOptions, Generated, Rng};
+
+/*
+About 450 towel patterns of one to eight stripes, none of them ending in `w`, so designs with
+`ww` in them or ending in `w` can't be made. `size` designs (400 by default) of up to 60 stripes: half are joined from patterns and
+can always be made, the rest are random stripes. The answers count the ways to make each design
+with a table over its prefixes.
+*/
+
+const STRIPES: &[u8] = b"wubrg";
+
+fn stripes(rng: &mut Rng, len: usize) -> String {
+    (0..len)
+        .map(|_| char::from(STRIPES[rng.usize(..STRIPES.len())]))
+        .collect()
+}
+
+fn ways(design: &str, patterns: &[String]) -> u128 {
+    let mut ways = vec![0u128; design.len() + 1];
+    ways[0] = 1;
+    for end in 1..=design.len() {
+        ways[end] = patterns
+            .iter()
+            .filter(|pattern| design[..end].ends_with(pattern.as_str()))
+            .map(|pattern| ways[end - pattern.len()])
+            .sum();
+    }
+    ways[design.len()]
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let mut patterns: Vec<String> = (0..450)
+        .map(|_| {
+            let len = rng.usize(1..=8);
+            stripes(&mut rng, len)
+        })
+        .filter(|pattern| !pattern.ends_with('w'))
+        .collect();
+    patterns.sort_unstable();
+    patterns.dedup();
+    rng.shuffle(&mut patterns);
+
+    let designs: Vec<String> = (0..options.size_or(400))
+        .map(|_| match rng.bool() {
+            true => {
+                let mut design = String::new();
+                while design.len() < 40 {
+                    design += &patterns[rng.usize(..patterns.len())];
+                }
+                design
+            }
+            false => {
+                let len = rng.usize(20..=60);
+                stripes(&mut rng, len)
+            }
+        })
+        .collect();
+
+    let counts: Vec<u128> = designs.iter().map(|design| ways(design, &patterns)).collect();
+    let input = format!("{}\n\n{}\n", patterns.join(", "), designs.join("\n"));
+    let mut generated = Generated::unknown(input).part1(counts.iter().filter(|&&n| n > 0).count());
+    if let Ok(total) = u64::try_from(counts.iter().sum::<u128>()) {
+        generated = generated.part2(total);
+    }
+    generated
+}
@@ -0,0 +1,117 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+use std::collections::{HashMap, HashSet};
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/19
+*/
+
+fn parse_file(contents: &str) -> ParseResult<(Vec<String>, Vec<String>)> {
+    let input = Input::new(19, contents);
+    let mut lines = contents.lines();
+    let patterns_line = lines
+        .next()
+        .filter(|line| !line.is_empty())
+        .ok_or_else(|| input.eof_error("expected a line of towel patterns"))?;
+    let patterns: Vec<String> = patterns_line.split(", ").map(String::from).collect();
+    if let Some(pattern) = patterns_line.split(", ").find(|p| p.is_empty() || p.contains(char::is_whitespace)) {
+        return Err(input.error(pattern, "expected a non-empty towel pattern"));
+    }
+    let designs: Vec<String> = lines.filter(|line| !line.is_empty()).map(String::from).collect();
+    Ok((patterns, designs))
+}
+
+fn can_construct(design: &str, patterns: &HashSet<&str>, memo: &mut HashMap<String, bool>) -> bool {
+    if design.is_empty() {
+        return true;
+    }
+    if let Some(&result) = memo.get(design) {
+        return result;
+    }
+    for pattern in patterns {
+        if design.starts_with(pattern) && can_construct(&design[pattern.len()..], patterns, memo) {
+            memo.insert(design.to_string(), true);
+            return true;
+        }
+    }
+    memo.insert(design.to_string(), false);
+    false
+}
+
+fn count_ways(design: &str, patterns: &HashSet<&str>, memo: &mut HashMap<String, usize>) -> usize {
+    if design.is_empty() {
+        return 1;
+    }
+    if let Some(&result) = memo.get(design) {
+        return result;
+    }
+    let mut total_ways = 0;
+    for pattern in patterns {
+        if let Some(rest) = design.strip_prefix(pattern) {
+            total_ways += count_ways(rest, patterns, memo);
+        }
+    }
+    memo.insert(design.to_string(), total_ways);
+    total_ways
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_file(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let (patterns, designs) = timed_parse(|| parse_file(contents))?;
+    let patterns_set: HashSet<&str> = patterns.iter().map(String::as_str).collect();
+    let mut memo = HashMap::new();
+    let mut count: usize = 0;
+
+    for design in designs {
+        if can_construct(&design, &patterns_set, &mut memo) {
+            count += 1;
+        }
+    }
+
+    Ok(count.into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let (patterns, designs) = timed_parse(|| parse_file(contents))?;
+    let patterns_set: HashSet<&str> = patterns.iter().map(String::as_str).collect();
+    let mut memo = HashMap::new();
+    let mut total_count = 0;
+
+    for design in designs {
+        total_count += count_ways(&design, &patterns_set, &mut memo);
+    }
+
+    Ok(total_count.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(6));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(16));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 20);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
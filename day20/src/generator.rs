@@ -0,0 +1,40 @@
+use crate::MIN_SAVING;
+use common::generator::{maze_path, perfect_maze, render, GenOptions, Generated};
+
+/*
+A racetrack through a `size` x `size` map (141 by default, rounded up to odd): the path between
+two corners of a perfect maze, with every tile off it walled in again, so there is exactly one
+track. The answers pair up every two tiles of the track and count the cheats, through walls at
+most 2 or 20 steps long, that skip far enough ahead along it.
+*/
+
+fn cheats(track: &[(usize, usize)], max_step: usize) -> usize {
+    let mut count = 0;
+    for (i, &(x0, y0)) in track.iter().enumerate() {
+        for (j, &(x1, y1)) in track.iter().enumerate().skip(i + MIN_SAVING) {
+            let distance = x0.abs_diff(x1) + y0.abs_diff(y1);
+            if distance <= max_step && j - i - distance >= MIN_SAVING {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let maze = perfect_maze(&mut rng, options.size_or(141));
+    let n = maze.len();
+    let (start, end) = ((1, n - 2), (n - 2, 1));
+    let track = maze_path(&maze, start, end);
+
+    let mut rows = vec![vec![b'#'; n]; n];
+    for &(x, y) in &track {
+        rows[y][x] = b'.';
+    }
+    rows[start.1][start.0] = b'S';
+    rows[end.1][end.0] = b'E';
+    Generated::unknown(render(&rows))
+        .part1(cheats(&track, 2))
+        .part2(cheats(&track, 20))
+}
@@ -0,0 +1,107 @@
+use anyhow::{anyhow, Result};
+use common::{timed_parse, Answer, Input, ParseResult};
+use grid::{Grid, Pos};
+use itertools::Itertools;
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/20
+*/
+
+const MIN_SAVING: usize = 100; // The example only has cheats saving up to 76 picoseconds
+
+fn read_map(contents: &str) -> ParseResult<Grid<u8>> {
+    let input = Input::new(20, contents);
+    let lines = input.grid_lines()?;
+    Ok(Grid::from_lines(&lines, |c| c as u8))
+}
+
+fn find_start_end(grid: &Grid<u8>) -> Result<(Pos, Pos)> {
+    Ok((
+        grid.find(&b'S').ok_or_else(|| anyhow!("No start `S` in the racetrack"))?,
+        grid.find(&b'E').ok_or_else(|| anyhow!("No end `E` in the racetrack"))?,
+    ))
+}
+
+/// Counts the cheats of up to `max_step` picoseconds that save at least `min_saving`.
+fn cheats_saving(grid: &Grid<u8>, start: Pos, end: Pos, max_step: usize, min_saving: usize) -> usize {
+    let track = search::bfs_all(start, |&position| {
+        let on_track = position != end;
+        grid.neighbours4(position)
+            .filter(move |&next| on_track && grid[next] != b'#')
+    });
+    let distances = track.costs;
+
+    let mut result = 0;
+    for ((&p1, &n1), (&p2, &n2)) in distances.iter().tuple_combinations() {
+        let manhattan_dist = p1.manhattan(p2) as usize;
+        if manhattan_dist > max_step {
+            continue;
+        }
+
+        let time_saved = n2.abs_diff(n1) - manhattan_dist;
+        if time_saved >= min_saving {
+            result += 1;
+        }
+    }
+
+    result
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    let grid = read_map(contents)?;
+    find_start_end(&grid)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let grid = timed_parse(|| read_map(contents))?;
+    let (start, end) = find_start_end(&grid)?;
+
+    let result = cheats_saving(&grid, start, end, 2, MIN_SAVING);
+    Ok(result.into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let grid = timed_parse(|| read_map(contents))?;
+    let (start, end) = find_start_end(&grid)?;
+
+    let result = cheats_saving(&grid, start, end, 20, MIN_SAVING);
+    Ok(result.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn count_cheats(max_step: usize, min_saving: usize) -> usize {
+        let grid = read_map(EXAMPLE).unwrap();
+        let (start, end) = find_start_end(&grid).unwrap();
+        cheats_saving(&grid, start, end, max_step, min_saving)
+    }
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(count_cheats(2, 1), 44);
+        assert_eq!(count_cheats(2, 20), 5);
+        assert_eq!(count_cheats(2, 64), 1);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(count_cheats(20, 50), 285);
+        assert_eq!(count_cheats(20, 76), 3);
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 41);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
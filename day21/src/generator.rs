@@ -0,0 +1,86 @@
+use crate::{DIRECTIONAL_PAD, GAP, NUMERIC_PAD};
+use common::generator::{GenOptions, Generated};
+use std::collections::{HashSet, VecDeque};
+
+/*
+`size` door codes (5 by default) of three random digits and an `A`. Part 1 is checked with a
+breadth-first search over every button the human can press, tracking the arms of all three
+robots and how much of the code has been typed. Part 2 chains too many robots for that.
+*/
+
+type Pad = &'static [&'static str];
+
+fn key(pad: Pad, (x, y): (usize, usize)) -> Option<u8> {
+    let key = *pad.get(y)?.as_bytes().get(x)?;
+    (key != GAP as u8).then_some(key)
+}
+
+fn find(pad: Pad, wanted: u8) -> (usize, usize) {
+    (0..pad.len())
+        .flat_map(|y| (0..3).map(move |x| (x, y)))
+        .find(|&at| key(pad, at) == Some(wanted))
+        .expect("every pad has the key")
+}
+
+/// Moves an arm by an arrow, if it stays over a key.
+fn step(pad: Pad, (x, y): (usize, usize), arrow: u8) -> Option<(usize, usize)> {
+    let next = match arrow {
+        b'^' => (x, y.checked_sub(1)?),
+        b'v' => (x, y + 1),
+        b'<' => (x.checked_sub(1)?, y),
+        _ => (x + 1, y),
+    };
+    key(pad, next).map(|_| next)
+}
+
+/// The arms of the two directional robots and the door robot, and how many keys of the code are
+/// typed.
+type State = ((usize, usize), (usize, usize), (usize, usize), usize);
+
+/// Presses a button on the human's keypad, unless an arm would leave its keypad or the door gets
+/// a wrong key.
+fn press((first, second, door, typed): State, button: u8, code: &[u8]) -> Option<State> {
+    if button != b'A' {
+        return Some((step(&DIRECTIONAL_PAD, first, button)?, second, door, typed));
+    }
+    match key(&DIRECTIONAL_PAD, first)? {
+        b'A' => match key(&DIRECTIONAL_PAD, second)? {
+            b'A' => {
+                (key(&NUMERIC_PAD, door) == Some(code[typed])).then_some((first, second, door, typed + 1))
+            }
+            arrow => Some((first, second, step(&NUMERIC_PAD, door, arrow)?, typed)),
+        },
+        arrow => Some((first, step(&DIRECTIONAL_PAD, second, arrow)?, door, typed)),
+    }
+}
+
+/// Fewest presses on the human's keypad that get `code` typed on the door.
+fn presses(code: &[u8]) -> u64 {
+    let arm = find(&DIRECTIONAL_PAD, b'A');
+    let start = (arm, arm, find(&NUMERIC_PAD, b'A'), 0);
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, count)) = queue.pop_front() {
+        if state.3 == code.len() {
+            return count;
+        }
+        for button in *b"^v<>A" {
+            if let Some(next) = press(state, button, code).filter(|next| seen.insert(*next)) {
+                queue.push_back((next, count + 1));
+            }
+        }
+    }
+    unreachable!("every code can be typed")
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let codes: Vec<String> = (0..options.size_or(5))
+        .map(|_| format!("{:03}A", rng.u16(..1000)))
+        .collect();
+    let complexity: u64 = codes
+        .iter()
+        .map(|code| presses(code.as_bytes()) * code[..3].parse::<u64>().expect("three digits"))
+        .sum();
+    Generated::unknown(codes.join("\n") + "\n").part1(complexity)
+}
@@ -0,0 +1,200 @@
+use anyhow::{anyhow, Result};
+use common::{timed_parse, Answer, Input, ParseResult};
+use grid::{Dir, Grid, Pos};
+use std::collections::HashMap;
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/21
+*/
+
+const DIRECTIONAL_PAD: [&str; 2] = ["X^A", "<v>"];
+const NUMERIC_PAD: [&str; 4] = ["789", "456", "123", "X0A"];
+/// The keypad tile no robot arm may point at.
+const GAP: char = 'X';
+
+struct Keypad {
+    keys: Grid<char>,
+}
+
+impl Keypad {
+    fn new(rows: &[&str]) -> Self {
+        Self {
+            keys: Grid::from_lines(rows, |c| c),
+        }
+    }
+
+    fn position(&self, key: char) -> Option<Pos> {
+        self.keys.find(&key)
+    }
+
+    /// Every shortest way to move the arm from `from` to `to` around the gap, as the arrow
+    /// presses followed by the `A` that pushes the key.
+    fn press_sequences(&self, from: Pos, to: Pos) -> Vec<String> {
+        let moves = search::bfs_all(from, |&p| {
+            self.keys.neighbours4(p).filter(|&next| self.keys[next] != GAP)
+        });
+        moves
+            .all_paths_to(&to)
+            .into_iter()
+            .map(|path| {
+                path.windows(2)
+                    .map(|step| arrow(step[1] - step[0]))
+                    .chain(std::iter::once('A'))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+fn arrow(offset: Pos) -> char {
+    Dir::ALL
+        .into_iter()
+        .find(|dir| dir.offset() == offset)
+        .map(Dir::arrow)
+        .expect("keypad moves are single steps")
+}
+
+struct Robots {
+    numeric: Keypad,
+    directional: Keypad,
+    /// Cheapest number of presses to move a directional arm between two keys and push the
+    /// second, for a chain of robots.
+    memo: HashMap<(Pos, Pos, i32), i64>,
+}
+
+impl Robots {
+    fn new() -> Self {
+        Self {
+            numeric: Keypad::new(&NUMERIC_PAD),
+            directional: Keypad::new(&DIRECTIONAL_PAD),
+            memo: HashMap::new(),
+        }
+    }
+
+    /// Where a key of the directional pad is; the presses come from `press_sequences`.
+    fn arrow_key(&self, press: char) -> Pos {
+        self.directional.position(press).expect("presses are directional keys")
+    }
+
+    fn cheapest_robot(&mut self, presses: &str, nrobots: i32) -> i64 {
+        if nrobots == 1 {
+            return presses.len() as i64;
+        }
+
+        let mut result = 0;
+        let mut curr = self.arrow_key('A');
+        for press in presses.chars() {
+            let next = self.arrow_key(press);
+            result += self.cheapest_dir_pad(curr, next, nrobots);
+            curr = next;
+        }
+        result
+    }
+
+    fn cheapest_dir_pad(&mut self, curr: Pos, dest: Pos, nrobots: i32) -> i64 {
+        if let Some(&result) = self.memo.get(&(curr, dest, nrobots)) {
+            return result;
+        }
+
+        let answer = self
+            .directional
+            .press_sequences(curr, dest)
+            .iter()
+            .map(|presses| self.cheapest_robot(presses, nrobots - 1))
+            .min()
+            .unwrap_or(i64::MAX);
+        self.memo.insert((curr, dest, nrobots), answer);
+        answer
+    }
+
+    fn cheapest(&mut self, curr: Pos, dest: Pos, layers: i32) -> i64 {
+        self.numeric
+            .press_sequences(curr, dest)
+            .iter()
+            .map(|presses| self.cheapest_robot(presses, layers))
+            .min()
+            .unwrap_or(i64::MAX)
+    }
+}
+
+fn parse_codes(contents: &str) -> ParseResult<Vec<(&str, i64)>> {
+    let input = Input::new(21, contents);
+    contents
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|line| match line.strip_suffix('A') {
+            Some(digits) if digits.len() == 3 && digits.bytes().all(|b| b.is_ascii_digit()) => {
+                Ok((line, input.parse(digits)?))
+            }
+            _ => Err(input.error(line, "expected a door code like `029A`")),
+        })
+        .collect()
+}
+
+fn complexity_sum(codes: &[(&str, i64)], layers: i32) -> Result<i64> {
+    let mut sum = 0i64;
+    let mut robots = Robots::new();
+
+    for &(line, code) in codes {
+        let mut result = 0i64;
+        let mut curr = robots.numeric.position('A').expect("the numeric keypad has an `A`");
+
+        for ch in line.chars() {
+            let next = robots
+                .numeric
+                .position(ch)
+                .ok_or_else(|| anyhow!("No key `{}` on the numeric keypad", ch))?;
+            result += robots.cheapest(curr, next, layers);
+            curr = next;
+        }
+
+        sum += result * code;
+    }
+
+    Ok(sum)
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_codes(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let codes = timed_parse(|| parse_codes(contents))?;
+    let sum = complexity_sum(&codes, 3)?;
+    sum.try_into()
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let codes = timed_parse(|| parse_codes(contents))?;
+    let sum = complexity_sum(&codes, 26)?;
+    sum.try_into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(126384));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::Number(154115708116294));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 5);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
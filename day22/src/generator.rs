@@ -0,0 +1,38 @@
+use crate::next_secret;
+use common::generator::{GenOptions, Generated};
+
+/*
+`size` buyers (2000 by default) with random initial secrets below 2^24. Part 1 sums every
+buyer's 2000th secret. Part 2 keeps a flat table with one slot per sequence of four price
+changes, adding each buyer's price at the first time the sequence shows up.
+*/
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let secrets: Vec<u64> = (0..options.size_or(2000))
+        .map(|_| rng.u64(1..16_777_216))
+        .collect();
+
+    let mut total = 0;
+    let mut bananas = vec![0u64; 19usize.pow(4)];
+    let mut first_buyer = vec![usize::MAX; 19usize.pow(4)];
+    for (buyer, &initial) in secrets.iter().enumerate() {
+        let mut secret = initial;
+        let mut changes = 0;
+        for i in 0..2000 {
+            let next = next_secret(secret);
+            let price = next % 10;
+            changes = (changes * 19 + (price + 9 - secret % 10) as usize) % 19usize.pow(4);
+            if i >= 3 && first_buyer[changes] != buyer {
+                first_buyer[changes] = buyer;
+                bananas[changes] += price;
+            }
+            secret = next;
+        }
+        total += secret;
+    }
+
+    let input: String = secrets.iter().map(|secret| format!("{}\n", secret)).collect();
+    let best = bananas.into_iter().max().unwrap_or(0);
+    Generated::unknown(input).part1(total).part2(best)
+}
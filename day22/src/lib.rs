@@ -0,0 +1,162 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+use std::collections::HashMap;
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/22
+*/
+
+#[inline(always)]
+fn next_secret(mut secret: u64) -> u64 {
+    secret = (secret ^ (secret * 64)) % 16777216;
+    secret = (secret ^ (secret / 32)) % 16777216;
+    secret = (secret ^ (secret * 2048)) % 16777216;
+    secret
+}
+
+fn get_nth_secret(initial: u64, n: usize) -> u64 {
+    let mut secret = initial;
+    for _ in 0..n {
+        secret = next_secret(secret);
+    }
+    secret
+}
+
+fn solve_part1(initials: &[u64]) -> u64 {
+    initials
+        .iter()
+        .map(|&initial| get_nth_secret(initial, 2000))
+        .sum()
+}
+
+fn get_sequence(initial: u64) -> Vec<i32> {
+    let mut seq = Vec::with_capacity(2001);
+    let mut current = initial;
+    seq.push((current % 10) as i32);
+
+    for _ in 0..2000 {
+        current = next_secret(current);
+        seq.push((current % 10) as i32);
+    }
+    seq
+}
+
+fn parse_input(contents: &str) -> ParseResult<Vec<u64>> {
+    let input = Input::new(22, contents);
+    contents
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let secret: u64 = input.parse(line)?;
+            // Secrets are pruned to 24 bits, larger seeds would overflow the first mix.
+            if secret >= 16777216 {
+                return Err(input.error(line, "expected a secret below 16777216"));
+            }
+            Ok(secret)
+        })
+        .collect()
+}
+
+fn solve_part2(initials: &[u64]) -> i32 {
+    let mut pattern_values = HashMap::new();
+
+    // Buyers can share an initial secret, and each of them buys.
+    for (buyer, &initial) in initials.iter().enumerate() {
+        let prices = get_sequence(initial);
+        let diffs: Vec<_> = prices.windows(2)
+            .map(|w| w[1] - w[0])
+            .collect();
+
+        for i in 0..diffs.len().saturating_sub(3) {
+            let pattern = (diffs[i], diffs[i + 1], diffs[i + 2], diffs[i + 3]);
+            pattern_values.entry(pattern)
+                .or_insert_with(HashMap::new)
+                .entry(buyer)
+                .or_insert(prices[i + 4]);
+        }
+    }
+
+    pattern_values.values()
+        .map(|init_map| init_map.values().sum::<i32>())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_input(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let initials = timed_parse(|| parse_input(contents))?;
+    let result = solve_part1(&initials);
+    Ok(result.into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let initials = timed_parse(|| parse_input(contents))?;
+    solve_part2(&initials).try_into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn test_example() {
+        let example = "1\n10\n100\n2024\n";
+        assert_eq!(solve_part1(&parse_input(example).unwrap()), 37327623);
+    }
+
+    #[test]
+    fn test_single_sequence() {
+        let secret = 123;
+        let expected = vec![
+            15887950, 16495136, 527345, 704524, 1553684,
+            12683156, 11100544, 12249484, 7753432, 5908254
+        ];
+
+        let mut current = secret;
+        for &expected_value in &expected {
+            current = next_secret(current);
+            assert_eq!(current, expected_value);
+        }
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let example = "1\n2\n3\n2024\n";
+        let initials = parse_input(example).unwrap();
+        assert_eq!(solve_part2(&initials), 23);
+    }
+
+    #[test]
+    fn test_repeated_secret_part2() {
+        let once = solve_part2(&parse_input("1\n").unwrap());
+        assert_eq!(solve_part2(&parse_input("1\n1\n").unwrap()), 2 * once);
+    }
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(37327623));
+    }
+
+    #[test]
+    fn test_example2_part2() {
+        assert_eq!(part2(EXAMPLE_2).unwrap(), Answer::Number(23));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 10);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
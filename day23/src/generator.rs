@@ -0,0 +1,76 @@
+use common::generator::{GenOptions, Generated};
+use std::collections::BTreeSet;
+
+/*
+A LAN of `size` computers (520 by default, at most 676 two-letter names) with a clique of 13
+planted. The other computers are split into fully linked groups of 12, and about four random
+links per computer join the groups, far too few to grow any of them to 13. The password is the
+planted clique. Part 1 counts the triangles with a computer starting with `t`, walking each
+link's common neighbours.
+*/
+
+const CLIQUE: usize = 13;
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let mut names: Vec<String> = (b'a'..=b'z')
+        .flat_map(|first| {
+            (b'a'..=b'z').map(move |second| String::from_utf8_lossy(&[first, second]).into_owned())
+        })
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(options.size_or(520).clamp(CLIQUE, names.len()));
+    let n = names.len();
+
+    let mut links = BTreeSet::new();
+    let groups = std::iter::once(0..CLIQUE).chain(
+        (CLIQUE..n)
+            .step_by(CLIQUE - 1)
+            .map(|start| start..n.min(start + CLIQUE - 1)),
+    );
+    for group in groups {
+        for a in group.clone() {
+            for b in a + 1..group.end {
+                links.insert((a, b));
+            }
+        }
+    }
+    for _ in 0..n * 2 {
+        let (a, b) = (rng.usize(..n), rng.usize(..n));
+        // Links into the clique stay rare, so the search for it isn't led astray.
+        if a != b && (a.max(b) >= CLIQUE && (a.min(b) >= CLIQUE || rng.u8(..4) == 0)) {
+            links.insert((a.min(b), a.max(b)));
+        }
+    }
+
+    let mut neighbours = vec![BTreeSet::new(); n];
+    for &(a, b) in &links {
+        neighbours[a].insert(b);
+        neighbours[b].insert(a);
+    }
+    let triangles = links
+        .iter()
+        .flat_map(|&(a, b)| {
+            neighbours[a]
+                .intersection(&neighbours[b])
+                .map(move |&c| (a, b, c))
+        })
+        .filter(|&(_, b, c)| c > b)
+        .filter(|&(a, b, c)| [a, b, c].iter().any(|&i| names[i].starts_with('t')))
+        .count();
+
+    let mut password: Vec<&str> = names[..CLIQUE].iter().map(String::as_str).collect();
+    password.sort_unstable();
+
+    let mut lines: Vec<String> = links
+        .iter()
+        .map(|&(a, b)| match rng.bool() {
+            true => format!("{}-{}", names[a], names[b]),
+            false => format!("{}-{}", names[b], names[a]),
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    Generated::unknown(lines.join("\n") + "\n")
+        .part1(triangles)
+        .part2(password.join(","))
+}
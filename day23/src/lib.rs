@@ -0,0 +1,189 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+use std::collections::{HashMap, HashSet};
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/23
+*/
+
+type Graph<'a> = HashMap<&'a str, HashSet<&'a str>>;
+
+fn parse_input(contents: &str) -> ParseResult<Graph<'_>> {
+    let input = Input::new(23, contents);
+    let mut graph: Graph = HashMap::new();
+
+    for line in contents.lines() {
+        if line.is_empty() { continue; }
+        let (a, b) = line
+            .split_once('-')
+            .filter(|(a, b)| !a.is_empty() && !b.is_empty())
+            .ok_or_else(|| input.error(line, "expected a connection like `kh-tc`"))?;
+        graph.entry(a).or_default().insert(b);
+        graph.entry(b).or_default().insert(a);
+    }
+    Ok(graph)
+}
+
+/// Triangles with a computer starting with `t`, found from each link through the common
+/// neighbours of its ends, each triangle once from its link between the two smallest names.
+fn find_connected_triples(graph: &Graph) -> usize {
+    let mut t_triples = 0;
+
+    for (&a, neighbours) in graph {
+        for &b in neighbours.iter().filter(|&&b| a < b) {
+            t_triples += neighbours
+                .intersection(&graph[b])
+                .filter(|&&c| b < c)
+                .filter(|&&c| [a, b, c].iter().any(|name| name.starts_with('t')))
+                .count();
+        }
+    }
+
+    t_triples
+}
+
+/// Bron-Kerbosch with pivoting: grows `clique` from the `candidates` that are linked to all of
+/// it, skipping the `excluded` ones whose cliques were already reported.
+fn bron_kerbosch<'a>(
+    graph: &Graph<'a>,
+    clique: &mut Vec<&'a str>,
+    mut candidates: HashSet<&'a str>,
+    mut excluded: HashSet<&'a str>,
+    largest: &mut Vec<&'a str>,
+) {
+    if candidates.is_empty() {
+        if excluded.is_empty() && clique.len() > largest.len() {
+            *largest = clique.clone();
+        }
+        return;
+    }
+    let pivot = candidates
+        .iter()
+        .chain(&excluded)
+        .max_by_key(|&&node| graph[node].len())
+        .copied()
+        .expect("candidates is not empty");
+    let branches: Vec<&str> = candidates.difference(&graph[pivot]).copied().collect();
+    for node in branches {
+        clique.push(node);
+        let neighbours = &graph[node];
+        bron_kerbosch(
+            graph,
+            clique,
+            candidates.intersection(neighbours).copied().collect(),
+            excluded.intersection(neighbours).copied().collect(),
+            largest,
+        );
+        clique.pop();
+        candidates.remove(node);
+        excluded.insert(node);
+    }
+}
+
+fn find_largest_clique(graph: &Graph) -> String {
+    let mut largest_clique = Vec::new();
+    let nodes: HashSet<&str> = graph.keys().copied().collect();
+    bron_kerbosch(graph, &mut Vec::new(), nodes, HashSet::new(), &mut largest_clique);
+
+    // The password lists the computers in alphabetical order.
+    largest_clique.sort_unstable();
+    largest_clique.join(",")
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_input(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let graph = timed_parse(|| parse_input(contents))?;
+    let result = find_connected_triples(&graph);
+    Ok(result.into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let graph = timed_parse(|| parse_input(contents))?;
+    let password = find_largest_clique(&graph);
+    Ok(password.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(7));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(part2(EXAMPLE).unwrap(), Answer::from("co,de,ka,ta"));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 60);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+
+    /// Every three computers, checked for links between all of them.
+    fn naive_triples(graph: &Graph) -> usize {
+        let nodes: Vec<&str> = graph.keys().copied().collect();
+        let mut t_triples = 0;
+        for i in 0..nodes.len() {
+            for j in i + 1..nodes.len() {
+                for k in j + 1..nodes.len() {
+                    let (a, b, c) = (nodes[i], nodes[j], nodes[k]);
+                    let linked = graph[a].contains(b) && graph[a].contains(c) && graph[b].contains(c);
+                    if linked && [a, b, c].iter().any(|name| name.starts_with('t')) {
+                        t_triples += 1;
+                    }
+                }
+            }
+        }
+        t_triples
+    }
+
+    /// Size of the largest set of computers that are all linked, over every subset of them.
+    fn naive_largest_clique(graph: &Graph) -> usize {
+        let nodes: Vec<&str> = graph.keys().copied().collect();
+        (0u32..1 << nodes.len())
+            .filter(|set| {
+                let members: Vec<&str> = (0..nodes.len()).filter(|i| set & 1 << i != 0).map(|i| nodes[i]).collect();
+                members.iter().all(|a| members.iter().all(|b| a == b || graph[a].contains(b)))
+            })
+            .map(u32::count_ones)
+            .max()
+            .unwrap_or(0) as usize
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_naive(links in vec((0usize..12, 0usize..12), 1..40)) {
+            // Few names, a quarter of them starting with `t`
+            const NAMES: [&str; 12] = ["ta", "tb", "tc", "ab", "cd", "de", "ef", "fg", "gh", "hi", "ij", "jk"];
+            let contents: String = links
+                .iter()
+                .filter(|(a, b)| a != b)
+                .map(|&(a, b)| format!("{}-{}\n", NAMES[a], NAMES[b]))
+                .collect();
+            let graph = parse_input(&contents).unwrap();
+            prop_assert_eq!(find_connected_triples(&graph), naive_triples(&graph));
+
+            let password = find_largest_clique(&graph);
+            let clique: Vec<&str> = password.split(',').filter(|name| !name.is_empty()).collect();
+            prop_assert_eq!(clique.len(), naive_largest_clique(&graph));
+            prop_assert!(clique.iter().all(|a| clique.iter().all(|b| a == b || graph[a].contains(b))));
+        }
+    }
+}
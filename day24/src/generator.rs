@@ -0,0 +1,120 @@
+use common::generator::{GenOptions, Generated, Rng};
+use std::collections::{BTreeSet, HashMap};
+
+/*
+A ripple-carry adder of `size` bits (45 by default, at most 63 so the sum fits a u64) with four
+pairs of gate outputs swapped, each pair within one full adder as in the real puzzle: the two
+input gates with each other, or the sum bit with the input AND gate, the carry AND gate or the
+carry out. Part 2 is the planted swaps; part 1 evaluates the miswired adder on random inputs.
+*/
+
+const SWAPS: usize = 4;
+
+struct Gate {
+    inputs: [String; 2],
+    op: &'static str,
+    output: String,
+}
+
+fn wire_name(rng: &mut Rng, used: &mut BTreeSet<String>) -> String {
+    loop {
+        let name: String = (0..3).map(|_| char::from(rng.u8(b'a'..b'x'))).collect();
+        if used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+fn evaluate(wire: &str, drivers: &HashMap<&str, &Gate>, values: &mut HashMap<String, bool>) -> bool {
+    if let Some(&value) = values.get(wire) {
+        return value;
+    }
+    let gate = drivers[wire];
+    let (a, b) = (
+        evaluate(&gate.inputs[0], drivers, values),
+        evaluate(&gate.inputs[1], drivers, values),
+    );
+    let value = match gate.op {
+        "AND" => a && b,
+        "OR" => a || b,
+        _ => a != b,
+    };
+    values.insert(wire.to_string(), value);
+    value
+}
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let bits = options.size_or(45).clamp(SWAPS + 2, 63);
+    let mut used = BTreeSet::new();
+    let mut gates: Vec<Gate> = Vec::new();
+    let mut gate = |inputs: [String; 2], op, output: String| {
+        gates.push(Gate { inputs, op, output });
+        gates.len() - 1
+    };
+
+    // Per bit from 1: the gates computing x XOR y, x AND y, the sum, carry AND and carry out.
+    let mut adders = Vec::new();
+    let mut carry = wire_name(&mut rng, &mut used);
+    gate(["x00".into(), "y00".into()], "XOR", "z00".into());
+    gate(["x00".into(), "y00".into()], "AND", carry.clone());
+    for bit in 1..bits {
+        let (x, y) = (format!("x{:02}", bit), format!("y{:02}", bit));
+        let (both, one) = (wire_name(&mut rng, &mut used), wire_name(&mut rng, &mut used));
+        let pass = wire_name(&mut rng, &mut used);
+        let carry_out = match bit + 1 == bits {
+            true => format!("z{:02}", bits),
+            false => wire_name(&mut rng, &mut used),
+        };
+        adders.push([
+            gate([x.clone(), y.clone()], "XOR", one.clone()),
+            gate([x, y], "AND", both.clone()),
+            gate([carry.clone(), one.clone()], "XOR", format!("z{:02}", bit)),
+            gate([carry, one], "AND", pass.clone()),
+            gate([pass, both], "OR", carry_out.clone()),
+        ]);
+        carry = carry_out;
+    }
+
+    // Not in the last adder, whose carry out is the top output bit.
+    adders.pop();
+    rng.shuffle(&mut adders);
+    let mut swapped = Vec::new();
+    for [one, both, sum, pass, carry_out] in adders.into_iter().take(SWAPS) {
+        let (a, b) = match rng.u8(..4) {
+            0 => (one, both),
+            1 => (sum, both),
+            2 => (sum, pass),
+            _ => (sum, carry_out),
+        };
+        let output = std::mem::take(&mut gates[a].output);
+        gates[a].output = std::mem::replace(&mut gates[b].output, output);
+        swapped.extend([gates[a].output.clone(), gates[b].output.clone()]);
+    }
+    swapped.sort_unstable();
+
+    let mut values = HashMap::new();
+    let mut lines = Vec::new();
+    for wire in ["x", "y"] {
+        for bit in 0..bits {
+            let value = rng.bool();
+            lines.push(format!("{}{:02}: {}", wire, bit, u8::from(value)));
+            values.insert(format!("{}{:02}", wire, bit), value);
+        }
+    }
+    let drivers: HashMap<&str, &Gate> = gates.iter().map(|gate| (gate.output.as_str(), gate)).collect();
+    let output = (0..=bits).rev().fold(0u64, |z, bit| {
+        z << 1 | u64::from(evaluate(&format!("z{:02}", bit), &drivers, &mut values))
+    });
+
+    rng.shuffle(&mut gates);
+    lines.push(String::new());
+    for gate in &gates {
+        let [a, b] = &gate.inputs;
+        let (a, b) = if rng.bool() { (a, b) } else { (b, a) };
+        lines.push(format!("{} {} {} -> {}", a, gate.op, b, gate.output));
+    }
+    Generated::unknown(lines.join("\n") + "\n")
+        .part1(output)
+        .part2(swapped.join(","))
+}
@@ -0,0 +1,329 @@
+use anyhow::{anyhow, Result};
+use common::{timed_parse, Answer, Input, ParseResult};
+use std::collections::{HashMap, HashSet};
+use tracing::{debug, debug_span};
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/24
+*/
+
+#[derive(Debug, Clone)]
+enum Gate {
+    And(String, String, String),  // input1, input2, output
+    Or(String, String, String),
+    Xor(String, String, String),
+}
+
+fn parse_input(contents: &str) -> ParseResult<(HashMap<String, bool>, Vec<Gate>)> {
+    let input = Input::new(24, contents);
+    let mut initial_values = HashMap::new();
+    let mut gates = Vec::new();
+
+    let &[wires, gate_lines] = input.blocks().as_slice() else {
+        return Err(input.eof_error("expected a blank line between the wires and the gates"));
+    };
+
+    // Parse initial values
+    for line in wires.lines() {
+        if line.trim().is_empty() { continue; }
+        let (wire, value) = line
+            .split_once(':')
+            .ok_or_else(|| input.error(line, "expected `wire: 0` or `wire: 1`"))?;
+        let value = match value.trim() {
+            "0" => false,
+            "1" => true,
+            other => return Err(input.error(other, "expected a wire value of 0 or 1")),
+        };
+        initial_values.insert(wire.trim().to_string(), value);
+    }
+
+    // Parse gates
+    for line in gate_lines.lines() {
+        if line.trim().is_empty() { continue; }
+        let (inputs, output) = line
+            .split_once("->")
+            .ok_or_else(|| input.error(line, "expected `a OP b -> out`"))?;
+        let output = output.trim().to_string();
+
+        let &[input1, gate_type, input2] = inputs.split_whitespace().collect::<Vec<_>>().as_slice() else {
+            return Err(input.error(inputs.trim(), "expected two input wires around a gate"));
+        };
+        let (input1, input2) = (input1.to_string(), input2.to_string());
+
+        let gate = match gate_type {
+            "AND" => Gate::And(input1, input2, output),
+            "OR" => Gate::Or(input1, input2, output),
+            "XOR" => Gate::Xor(input1, input2, output),
+            _ => return Err(input.error(gate_type, "expected gate type AND, OR or XOR")),
+        };
+
+        gates.push(gate);
+    }
+
+    Ok((initial_values, gates))
+}
+
+fn simulate_circuit(initial_values: &HashMap<String, bool>, gates: &[Gate]) -> HashMap<String, bool> {
+    let mut values = initial_values.clone();
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+        for gate in gates {
+            match gate {
+                Gate::And(in1, in2, out) => {
+                    if let (Some(&val1), Some(&val2)) = (values.get(in1), values.get(in2)) {
+                        if !values.contains_key(out) {
+                            values.insert(out.clone(), val1 && val2);
+                            changed = true;
+                        }
+                    }
+                }
+                Gate::Or(in1, in2, out) => {
+                    if let (Some(&val1), Some(&val2)) = (values.get(in1), values.get(in2)) {
+                        if !values.contains_key(out) {
+                            values.insert(out.clone(), val1 || val2);
+                            changed = true;
+                        }
+                    }
+                }
+                Gate::Xor(in1, in2, out) => {
+                    if let (Some(&val1), Some(&val2)) = (values.get(in1), values.get(in2)) {
+                        if !values.contains_key(out) {
+                            values.insert(out.clone(), val1 != val2);
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    values
+}
+
+fn calculate_result(values: &HashMap<String, bool>) -> u64 {
+    let mut z_wires: Vec<(String, bool)> = values
+        .iter()
+        .filter(|(k, _)| k.starts_with('z'))
+        .map(|(k, &v)| (k.clone(), v))
+        .collect();
+
+    // Sort by wire number to ensure correct bit order
+    z_wires.sort_by(|a, b| {
+        let a_num = a.0[1..].parse::<u32>().unwrap_or(0);
+        let b_num = b.0[1..].parse::<u32>().unwrap_or(0);
+        b_num.cmp(&a_num)  // Changed from a_num.cmp(&b_num) to reverse the order
+    });
+
+    // For debugging
+    debug!("Ordered z-wires:");
+    for (wire, value) in &z_wires {
+        debug!("{}: {}", wire, value);
+    }
+
+    let mut result = 0;
+    for (_, value) in z_wires {
+        result = (result << 1) | (value as u64);
+    }
+
+    result
+}
+
+#[derive(Debug, Clone)]
+struct Circuit {
+    gates: Vec<(String, String, String, String)>, // (wire1, op, wire2, output)
+    bits: usize,
+}
+
+impl Circuit {
+    fn new(contents: &str) -> ParseResult<Self> {
+        let (_, gates) = parse_input(contents)?;
+        let gates = gates
+            .into_iter()
+            .map(|gate| match gate {
+                Gate::And(wire1, wire2, output) => (wire1, "AND".to_string(), wire2, output),
+                Gate::Or(wire1, wire2, output) => (wire1, "OR".to_string(), wire2, output),
+                Gate::Xor(wire1, wire2, output) => (wire1, "XOR".to_string(), wire2, output),
+            })
+            .collect::<Vec<_>>();
+        // One full adder per bit of the x input
+        let bits = gates
+            .iter()
+            .flat_map(|(wire1, _, wire2, _)| [wire1, wire2])
+            .filter(|wire| wire.starts_with('x'))
+            .collect::<HashSet<_>>()
+            .len();
+
+        Ok(Circuit { gates, bits })
+    }
+
+    fn find_gate(&self, a: &str, b: &str, op: &str) -> Option<String> {
+        for (wire1, gate_op, wire2, output) in &self.gates {
+            if (wire1 == a && wire2 == b || wire1 == b && wire2 == a) && gate_op == op {
+                return Some(output.clone());
+            }
+        }
+        None
+    }
+
+    fn find_swaps(&self) -> Result<Vec<String>> {
+        let mut swapped = HashSet::new();
+        let mut carry: Option<String> = None;
+
+        let top = format!("z{:02}", self.bits);
+
+        // Check each bit position
+        for i in 0..self.bits {
+            let _bit = debug_span!("bit", i).entered();
+            let n = format!("{:02}", i);
+            let x = format!("x{}", n);
+            let y = format!("y{}", n);
+            // A gate the adder for this bit has to have, even after swapping.
+            let expect = |wire: &Option<String>, gate: &str| {
+                wire.clone().ok_or_else(|| anyhow!("The adder for bit {} has no {} gate", i, gate))
+            };
+
+            // Find half adder components
+            let mut m1 = self.find_gate(&x, &y, "XOR");
+            let mut n1 = self.find_gate(&x, &y, "AND");
+
+            // Process carry chain if we have previous carry
+            if let Some(c0) = &carry {
+                if let (Some(m1_val), Some(n1_val)) = (&m1, &n1) {
+                    let mut r1 = self.find_gate(c0, m1_val, "AND");
+
+                    // If no r1 found, try swapping m1 and n1
+                    if r1.is_none() {
+                        debug!(a = %m1_val, b = %n1_val, "swapping wires");
+                        swapped.insert(m1_val.clone());
+                        swapped.insert(n1_val.clone());
+                        std::mem::swap(&mut m1, &mut n1);
+                        r1 = self.find_gate(c0, &expect(&m1, "x XOR y")?, "AND");
+                    }
+
+                    let mut z1 = self.find_gate(c0, &expect(&m1, "x XOR y")?, "XOR");
+
+                    // Check and swap z-prefixed wires
+                    if let Some(m1_val) = &m1 {
+                        if m1_val.starts_with('z') {
+                            debug!(a = %m1_val, b = %expect(&z1, "sum XOR")?, "swapping wires");
+                            swapped.insert(m1_val.clone());
+                            swapped.insert(expect(&z1, "sum XOR")?);
+                            std::mem::swap(&mut m1, &mut z1);
+                        }
+                    }
+
+                    if let Some(n1_val) = &n1 {
+                        if n1_val.starts_with('z') {
+                            debug!(a = %n1_val, b = %expect(&z1, "sum XOR")?, "swapping wires");
+                            swapped.insert(n1_val.clone());
+                            swapped.insert(expect(&z1, "sum XOR")?);
+                            std::mem::swap(&mut n1, &mut z1);
+                        }
+                    }
+
+                    if let Some(r1_val) = &r1 {
+                        if r1_val.starts_with('z') {
+                            debug!(a = %r1_val, b = %expect(&z1, "sum XOR")?, "swapping wires");
+                            swapped.insert(r1_val.clone());
+                            swapped.insert(expect(&z1, "sum XOR")?);
+                            std::mem::swap(&mut r1, &mut z1);
+                        }
+                    }
+
+                    // Find carry out
+                    let c1 = self.find_gate(&expect(&r1, "carry AND")?, &expect(&n1, "x AND y")?, "OR");
+
+                    // Check if carry needs to be swapped
+                    if let Some(c1_val) = &c1 {
+                        if c1_val.starts_with('z') && *c1_val != top {
+                            debug!(a = %c1_val, b = %expect(&z1, "sum XOR")?, "swapping wires");
+                            swapped.insert(c1_val.clone());
+                            swapped.insert(expect(&z1, "sum XOR")?);
+                            carry = Some(expect(&z1, "sum XOR")?);
+                            continue;
+                        }
+                    }
+                    carry = c1;
+                } else {
+                    carry = None;
+                }
+            } else {
+                // First bit - carry is just the AND gate output
+                carry = n1;
+            }
+        }
+
+        let mut result: Vec<_> = swapped.into_iter().collect();
+        result.sort();
+        Ok(result)
+    }
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_input(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let (initial_values, gates) = timed_parse(|| parse_input(contents))?;
+    let final_values = simulate_circuit(&initial_values, &gates);
+    let result = calculate_result(&final_values);
+
+    Ok(result.into())
+}
+
+pub fn part2(contents: &str) -> Result<Answer> {
+    let circuit = timed_parse(|| Circuit::new(contents))?;
+    let swapped = circuit.find_swaps()?;
+    Ok(swapped.join(",").into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(4));
+    }
+
+    #[test]
+    fn test_example2_part1() {
+        assert_eq!(part1(EXAMPLE_2).unwrap(), Answer::Number(2024));
+    }
+
+    #[test]
+    fn test_ninthprime() {
+        let input = include_str!("../ninthprime");
+        assert_eq!(part1(input).unwrap(), Answer::Number(45121475050728));
+        assert_eq!(part2(input).unwrap(), Answer::from("gqp,hsw,jmh,mwk,qgd,z10,z18,z33"));
+    }
+
+    #[test]
+    fn test_rejects_unknown_gate() {
+        let err = part1("x00: 1\ny00: 0\n\nx00 NAND y00 -> z00\n").unwrap_err();
+        assert!(err.to_string().starts_with("day 24, line 4, column 5: expected gate type AND, OR or XOR"));
+    }
+
+    #[test]
+    fn test_reports_missing_gates() {
+        let err = part2(include_str!("../regressions/missing-sum-gate.txt")).unwrap_err();
+        assert_eq!(err.to_string(), "The adder for bit 1 has no sum XOR gate");
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1, part2], generator::generate, 12);
+    }
+
+    common::robustness_test!(parse, [part1, part2], EXAMPLE);
+}
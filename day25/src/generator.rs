@@ -0,0 +1,36 @@
+use common::generator::{GenOptions, Generated};
+
+/*
+`size` schematics (500 by default), locks and keys alike with random pin heights of 0 to 5 in
+each of the five columns. The answer tries every lock against every key.
+*/
+
+pub fn generate(options: &GenOptions) -> Generated {
+    let mut rng = options.rng();
+    let (mut locks, mut keys) = (Vec::new(), Vec::new());
+    let mut schematics = Vec::new();
+
+    for _ in 0..options.size_or(500) {
+        let heights: [usize; 5] = std::array::from_fn(|_| rng.usize(0..=5));
+        let is_lock = rng.bool();
+        // Row 0 is the filled top row of a lock; keys fill their bottom row instead.
+        let rows: Vec<String> = (0..7)
+            .map(|row| {
+                let depth = if is_lock { row } else { 6 - row };
+                heights
+                    .iter()
+                    .map(|&h| if depth <= h { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        schematics.push(rows.join("\n") + "\n");
+        if is_lock { &mut locks } else { &mut keys }.push(heights);
+    }
+
+    let fits = locks
+        .iter()
+        .flat_map(|lock| keys.iter().map(move |key| (lock, key)))
+        .filter(|(lock, key)| lock.iter().zip(key.iter()).all(|(l, k)| l + k <= 5))
+        .count();
+    Generated::unknown(schematics.join("\n")).part1(fits)
+}
@@ -0,0 +1,119 @@
+use anyhow::Result;
+use common::{timed_parse, Answer, Input, ParseResult};
+
+pub mod generator;
+
+/*
+https://adventofcode.com/2024/day/25
+*/
+
+#[derive(Debug)]
+struct Pattern {
+    heights: Vec<usize>,
+    is_lock: bool,
+}
+
+impl Pattern {
+    fn parse(input: &Input, block: &str) -> ParseResult<Self> {
+        let mut lines: Vec<&str> = block.lines().filter(|line| !line.is_empty()).collect();
+        if lines.len() != 7 {
+            return Err(input.error(block.trim_end(), "expected a schematic of 7 rows"));
+        }
+        for line in &lines {
+            if line.len() != 5 {
+                return Err(input.error(line, "expected a row of width 5"));
+            }
+            if let Some((pos, c)) = line.char_indices().find(|&(_, c)| c != '#' && c != '.') {
+                return Err(input.error(&line[pos..pos + c.len_utf8()], "expected `#` or `.`"));
+            }
+        }
+        if lines[0] != "#####" && lines[6] != "#####" {
+            return Err(input.error(lines[0], "expected a lock (filled top row) or a key (filled bottom row)"));
+        }
+        let is_lock = lines[0].chars().filter(|&c| c == '#').count() == 5;
+
+        if !is_lock {
+            lines.reverse();
+        }
+
+        let mut heights = Vec::new();
+        let cols = lines[0].len();
+
+        // Process each column
+        for col in 0..cols {
+            let mut height = 0;
+            for line in &lines {
+                if line.chars().nth(col) == Some('#') {
+                    height += 1;
+                } else {
+                    break;
+                }
+            }
+            heights.push(height);
+        }
+
+        Ok(Self { heights, is_lock })
+    }
+}
+
+fn can_fit(key: &Pattern, lock: &Pattern) -> bool {
+    assert!(key.is_lock && !lock.is_lock);
+    assert_eq!(lock.heights.len(), key.heights.len());
+
+    key.heights.iter().zip(lock.heights.iter())
+        .all(|(&k, &l)| k + l <= 7)
+}
+
+fn parse_patterns(contents: &str) -> ParseResult<Vec<Pattern>> {
+    let input = Input::new(25, contents);
+    input
+        .blocks()
+        .into_iter()
+        .map(|block| Pattern::parse(&input, block))
+        .collect()
+}
+
+/// Runs only the parser, for the fuzz targets and the robustness tests.
+pub fn parse(contents: &str) -> Result<()> {
+    parse_patterns(contents)?;
+    Ok(())
+}
+
+pub fn part1(contents: &str) -> Result<Answer> {
+    let patterns = timed_parse(|| parse_patterns(contents))?;
+
+    let (keys, locks): (Vec<&Pattern>, Vec<&Pattern>) = patterns
+        .iter()
+        .partition(|p| p.is_lock);
+
+    let mut valid_count: usize = 0;
+    for key in keys.iter() {
+        for lock in locks.iter() {
+            if can_fit(key, lock) {
+                valid_count += 1;
+            }
+        }
+    }
+
+    Ok(valid_count.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generator::assert_checks;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(part1(EXAMPLE).unwrap(), Answer::Number(3));
+    }
+
+    #[test]
+    fn test_generated() {
+        assert_checks(&[part1], generator::generate, 40);
+    }
+
+    common::robustness_test!(parse, [part1], EXAMPLE);
+}
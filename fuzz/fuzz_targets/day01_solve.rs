@@ -0,0 +1,7 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|contents: &str| {
+    common::fuzz::assert_solves(day01::parse, &[day01::part1, day01::part2], contents);
+});
@@ -0,0 +1,7 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|contents: &str| {
+    let _ = day02::parse(contents);
+});
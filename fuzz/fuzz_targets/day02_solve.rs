@@ -0,0 +1,7 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|contents: &str| {
+    common::fuzz::assert_solves(day02::parse, &[day02::part1, day02::part2], contents);
+});
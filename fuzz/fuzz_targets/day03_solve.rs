@@ -0,0 +1,7 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|contents: &str| {
+    common::fuzz::assert_solves(day03::parse, &[day03::part1, day03::part2], contents);
+});
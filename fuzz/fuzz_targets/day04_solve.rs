@@ -0,0 +1,7 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|contents: &str| {
+    common::fuzz::assert_solves(day04::parse, &[day04::part1, day04::part2], contents);
+});
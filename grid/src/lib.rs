@@ -0,0 +1,386 @@
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Sub};
+
+/*
+Rectangular grids of puzzle maps.
+
+Cells live row by row in one flat buffer. Positions are signed, so stepping off the edge gives a
+position that `Grid::get` answers with `None` instead of wrapping around or underflowing.
+*/
+
+/// A cell position: `x` is the column, `y` the row, with y growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Pos {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn step(self, dir: Dir) -> Pos {
+        self + dir.offset()
+    }
+
+    pub fn manhattan(self, other: Pos) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The four orthogonal neighbours, in `Dir::ALL` order.
+    pub fn neighbours4(self) -> impl Iterator<Item = Pos> {
+        Dir::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+
+    /// The eight neighbours including diagonals, clockwise from north.
+    pub fn neighbours8(self) -> impl Iterator<Item = Pos> {
+        OFFSETS8.into_iter().map(move |offset| self + offset)
+    }
+}
+
+impl Add for Pos {
+    type Output = Pos;
+
+    fn add(self, other: Pos) -> Pos {
+        Pos::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Pos {
+    type Output = Pos;
+
+    fn sub(self, other: Pos) -> Pos {
+        Pos::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Offsets of the eight neighbours, clockwise from north.
+pub const OFFSETS8: [Pos; 8] = [
+    Pos::new(0, -1),
+    Pos::new(1, -1),
+    Pos::new(1, 0),
+    Pos::new(1, 1),
+    Pos::new(0, 1),
+    Pos::new(-1, 1),
+    Pos::new(-1, 0),
+    Pos::new(-1, -1),
+];
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Dir {
+    /// Clockwise from north.
+    pub const ALL: [Dir; 4] = [Dir::N, Dir::E, Dir::S, Dir::W];
+
+    /// The direction of an arrow `^`, `>`, `v` or `<`.
+    pub fn from_arrow(c: char) -> Option<Dir> {
+        match c {
+            '^' => Some(Dir::N),
+            '>' => Some(Dir::E),
+            'v' => Some(Dir::S),
+            '<' => Some(Dir::W),
+            _ => None,
+        }
+    }
+
+    /// The arrow `^`, `>`, `v` or `<` pointing this way.
+    pub fn arrow(self) -> char {
+        match self {
+            Dir::N => '^',
+            Dir::E => '>',
+            Dir::S => 'v',
+            Dir::W => '<',
+        }
+    }
+
+    pub fn offset(self) -> Pos {
+        match self {
+            Dir::N => Pos::new(0, -1),
+            Dir::E => Pos::new(1, 0),
+            Dir::S => Pos::new(0, 1),
+            Dir::W => Pos::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Dir {
+        Dir::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Dir {
+        Dir::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Dir {
+        Dir::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir::N | Dir::S)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` x `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from equally long lines, mapping every character to a cell.
+    ///
+    /// Panics if the lines differ in length; parsers check that first, e.g. with
+    /// `common::Input::grid_lines`.
+    pub fn from_lines(lines: &[&str], mut cell: impl FnMut(char) -> T) -> Self {
+        match Self::try_from_lines(lines, |_, c| Ok::<T, ()>(cell(c))) {
+            Ok(grid) => grid,
+            Err(()) => unreachable!(),
+        }
+    }
+
+    /// Like `from_lines`, with a mapping that can reject a character at a position.
+    pub fn try_from_lines<E>(
+        lines: &[&str],
+        mut cell: impl FnMut(Pos, char) -> Result<T, E>,
+    ) -> Result<Self, E> {
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let row_start = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                cells.push(cell(Pos::new(x as i64, y as i64), c)?);
+            }
+            assert_eq!(cells.len() - row_start, width, "row {} of the grid has a different width", y);
+        }
+        Ok(Self {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x >= 0 && pos.y >= 0 && (pos.x as usize) < self.width && (pos.y as usize) < self.height
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    /// The cell at `pos`, or `None` outside the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Pos::new(x, y)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The first position, row by row, holding `value`.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter().filter(move |(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+
+    /// The orthogonal neighbours of `pos` that lie inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbours4().filter(|&p| self.contains(p))
+    }
+
+    /// The neighbours of `pos`, diagonals included, that lie inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbours8().filter(|&p| self.contains(p))
+    }
+
+    /// A grid of the same shape with every cell mapped through `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |p| Pos::new(p.y, p.x))
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height as i64;
+        self.rearranged(self.height, self.width, |p| Pos::new(p.y, height - 1 - p.x))
+    }
+
+    /// Rotates the grid a quarter turn counter-clockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width as i64;
+        self.rearranged(self.height, self.width, |p| Pos::new(width - 1 - p.y, p.x))
+    }
+
+    /// A `width` x `height` grid whose cell at `p` is this grid's cell at `source(p)`.
+    fn rearranged(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for y in 0..height as i64 {
+            for x in 0..width as i64 {
+                cells.push(self[source(Pos::new(x, y))].clone());
+            }
+        }
+        Self { width, height, cells }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    /// Panics outside the grid; use `get` for positions that may be off the edge.
+    fn index(&self, pos: Pos) -> &T {
+        let i = self
+            .index_of(pos)
+            .unwrap_or_else(|| panic!("{} is outside the {}x{} grid", pos, self.width, self.height));
+        &self.cells[i]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        let i = self
+            .index_of(pos)
+            .unwrap_or_else(|| panic!("{} is outside the {}x{} grid", pos, width, height));
+        &mut self.cells[i]
+    }
+}
+
+/// Prints the grid one row per line, the way the puzzle shows it.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        let lines: Vec<&str> = text.lines().collect();
+        Grid::from_lines(&lines, |c| c)
+    }
+
+    #[test]
+    fn test_get_and_neighbours() {
+        let g = grid("ab\ncd\nef\n");
+        assert_eq!((g.width(), g.height()), (2, 3));
+        assert_eq!(g[Pos::new(1, 2)], 'f');
+        assert_eq!(g.get(Pos::new(-1, 0)), None);
+        assert_eq!(g.get(Pos::new(2, 0)), None);
+        assert_eq!(g.find(&'d'), Some(Pos::new(1, 1)));
+
+        let around_a: Vec<char> = g.neighbours4(Pos::new(0, 0)).map(|p| g[p]).collect();
+        assert_eq!(around_a, vec!['b', 'c']);
+        assert_eq!(g.neighbours8(Pos::new(0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let g = grid("abc\ndef\n");
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(g.rotate_right().to_string(), "da\neb\nfc\n");
+        assert_eq!(g.rotate_left().to_string(), "cf\nbe\nad\n");
+        assert_eq!(g.rotate_right().rotate_left(), g);
+    }
+
+    #[test]
+    fn test_try_from_lines_reports_position() {
+        let lines = ["12", "3x"];
+        let digits = Grid::try_from_lines(&lines, |pos, c| c.to_digit(10).ok_or(pos));
+        assert_eq!(digits, Err(Pos::new(1, 1)));
+    }
+
+    #[test]
+    fn test_dir_turns() {
+        assert_eq!(Dir::N.turn_right(), Dir::E);
+        assert_eq!(Dir::N.turn_left(), Dir::W);
+        assert_eq!(Dir::E.reverse(), Dir::W);
+        assert_eq!(Pos::new(3, 3).step(Dir::from_arrow('^').unwrap()), Pos::new(3, 2));
+        assert!(Dir::ALL.iter().all(|&d| Dir::from_arrow(d.arrow()) == Some(d)));
+    }
+}
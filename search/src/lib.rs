@@ -0,0 +1,385 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/*
+Graph searches over successor functions.
+
+A graph is never built up front: every search takes the start node and a closure listing the
+neighbours of a node, with their edge costs for the weighted searches. `Notes/DFSvsBFS.md`
+compares the traversal orders.
+*/
+
+/// Edge and path costs of the weighted searches. `Default` is the zero cost.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// Every node reachable from `start`, in breadth-first order.
+pub fn bfs_reach<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut order = vec![start];
+    let mut next = 0;
+    while let Some(node) = order.get(next).cloned() {
+        next += 1;
+        for successor in successors(&node) {
+            if seen.insert(successor.clone()) {
+                order.push(successor);
+            }
+        }
+    }
+    order
+}
+
+/// Every node reachable from `start`, in depth-first pre-order.
+pub fn dfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut order = Vec::new();
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        let mut next: Vec<N> = successors(&node).into_iter().filter(|n| !seen.contains(n)).collect();
+        // Reversed, so the first successor is explored first.
+        next.reverse();
+        stack.extend(next);
+        order.push(node);
+    }
+    order
+}
+
+/// A path with the fewest edges from `start` to a node satisfying `is_goal`, both ends included.
+pub fn bfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I, mut is_goal: impl FnMut(&N) -> bool) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(reconstruct_path(&parents, node));
+        }
+        for successor in successors(&node) {
+            if seen.insert(successor.clone()) {
+                parents.insert(successor.clone(), node.clone());
+                queue.push_back(successor);
+            }
+        }
+    }
+    None
+}
+
+/// Fewest-edge distances from `start` to every reachable node, with all shortest paths.
+pub fn bfs_all<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> ShortestPaths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = ShortestPaths::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let cost = paths.costs[&node] + 1;
+        for successor in successors(&node) {
+            match paths.costs.get(&successor) {
+                None => {
+                    paths.costs.insert(successor.clone(), cost);
+                    paths.predecessors.insert(successor.clone(), vec![node.clone()]);
+                    queue.push_back(successor);
+                }
+                Some(&known) if known == cost => {
+                    paths.predecessors.entry(successor).or_default().push(node.clone());
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    paths
+}
+
+/// A cheapest path from `start` to a node satisfying `is_goal`, and its cost.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// A cheapest path to a node satisfying `is_goal`, guided by `heuristic`.
+///
+/// The heuristic must never overestimate the remaining cost, or the path may not be cheapest.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut parents = HashMap::new();
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut heap = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    }]);
+    while let Some(Entry { cost, node, .. }) = heap.pop() {
+        if costs.get(&node).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((reconstruct_path(&parents, node), cost));
+        }
+        for (successor, step) in successors(&node) {
+            let new_cost = cost + step;
+            if costs.get(&successor).is_none_or(|&best| new_cost < best) {
+                costs.insert(successor.clone(), new_cost);
+                parents.insert(successor.clone(), node.clone());
+                heap.push(Entry {
+                    priority: new_cost + heuristic(&successor),
+                    cost: new_cost,
+                    node: successor,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Cheapest costs from `start` to every reachable node, with all cheapest paths.
+pub fn dijkstra_all<N, C, I>(start: N, mut successors: impl FnMut(&N) -> I) -> ShortestPaths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = ShortestPaths::new(start.clone());
+    let mut heap = BinaryHeap::from([Entry {
+        priority: C::default(),
+        cost: C::default(),
+        node: start,
+    }]);
+    while let Some(Entry { cost, node, .. }) = heap.pop() {
+        if paths.costs[&node] < cost {
+            continue;
+        }
+        for (successor, step) in successors(&node) {
+            let new_cost = cost + step;
+            match paths.costs.get(&successor).map(|known| new_cost.cmp(known)) {
+                None | Some(Ordering::Less) => {
+                    paths.costs.insert(successor.clone(), new_cost);
+                    paths.predecessors.insert(successor.clone(), vec![node.clone()]);
+                    heap.push(Entry {
+                        priority: new_cost,
+                        cost: new_cost,
+                        node: successor,
+                    });
+                }
+                Some(Ordering::Equal) => {
+                    paths.predecessors.entry(successor).or_default().push(node.clone());
+                }
+                Some(Ordering::Greater) => {}
+            }
+        }
+    }
+    paths
+}
+
+/// The path from the root of `parents` to `end`, following each node's parent.
+pub fn reconstruct_path<N: Clone + Eq + Hash>(parents: &HashMap<N, N>, end: N) -> Vec<N> {
+    let mut path = vec![end];
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Result of `bfs_all` and `dijkstra_all`: the cheapest cost of every reachable node and the
+/// predecessor DAG holding every cheapest path from the start.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N, C> {
+    pub start: N,
+    pub costs: HashMap<N, C>,
+    /// The nodes a cheapest path can come from; the start has none.
+    pub predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> ShortestPaths<N, C> {
+    fn new(start: N) -> Self {
+        Self {
+            costs: HashMap::from([(start.clone(), C::default())]),
+            predecessors: HashMap::new(),
+            start,
+        }
+    }
+
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// One cheapest path from the start to `end`.
+    pub fn path_to(&self, end: &N) -> Option<Vec<N>> {
+        self.costs.get(end)?;
+        let mut path = vec![end.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()).and_then(|p| p.first()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every cheapest path from the start to `end`.
+    pub fn all_paths_to(&self, end: &N) -> Vec<Vec<N>> {
+        if !self.costs.contains_key(end) {
+            return Vec::new();
+        }
+        let Some(previous) = self.predecessors.get(end) else {
+            return vec![vec![end.clone()]];
+        };
+        let mut paths = Vec::new();
+        for node in previous {
+            for mut path in self.all_paths_to(node) {
+                path.push(end.clone());
+                paths.push(path);
+            }
+        }
+        paths
+    }
+
+    /// The number of cheapest paths from the start to `end`.
+    pub fn count_paths(&self, end: &N) -> u64 {
+        fn count<N: Eq + Hash + Clone>(
+            predecessors: &HashMap<N, Vec<N>>,
+            node: &N,
+            memo: &mut HashMap<N, u64>,
+        ) -> u64 {
+            if let Some(&n) = memo.get(node) {
+                return n;
+            }
+            let n = match predecessors.get(node) {
+                None => 1,
+                Some(previous) => previous.iter().map(|p| count(predecessors, p, memo)).sum(),
+            };
+            memo.insert(node.clone(), n);
+            n
+        }
+        if !self.costs.contains_key(end) {
+            return 0;
+        }
+        count(&self.predecessors, end, &mut HashMap::new())
+    }
+
+    /// Every node on some cheapest path from the start to one of `ends`.
+    pub fn nodes_on_paths<'a>(&self, ends: impl IntoIterator<Item = &'a N>) -> HashSet<N>
+    where
+        N: 'a,
+    {
+        let mut on_path = HashSet::new();
+        let mut stack: Vec<N> = ends.into_iter().filter(|e| self.costs.contains_key(e)).cloned().collect();
+        while let Some(node) = stack.pop() {
+            if let Some(previous) = self.predecessors.get(&node) {
+                stack.extend(previous.iter().filter(|p| !on_path.contains(*p)).cloned());
+            }
+            on_path.insert(node);
+        }
+        on_path
+    }
+}
+
+/// Heap entry ordered so that `BinaryHeap`, a max-heap, pops the lowest priority first.
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 3, 0 -> 2 -> 3, 3 -> 4; 5 is unreachable.
+    fn successors(node: &u32) -> Vec<u32> {
+        match node {
+            0 => vec![1, 2],
+            1 | 2 => vec![3],
+            3 => vec![4],
+            5 => vec![0],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_traversals() {
+        assert_eq!(bfs_reach(0, successors), vec![0, 1, 2, 3, 4]);
+        assert_eq!(dfs(0, successors), vec![0, 1, 3, 4, 2]);
+        assert_eq!(bfs(0, successors, |&n| n == 4), Some(vec![0, 1, 3, 4]));
+        assert_eq!(bfs(0, successors, |&n| n == 5), None);
+    }
+
+    #[test]
+    fn test_shortest_paths_dag() {
+        let paths = bfs_all(0, successors);
+        assert_eq!(paths.cost(&4), Some(3));
+        assert_eq!(paths.cost(&5), None);
+        assert_eq!(paths.count_paths(&4), 2);
+        assert_eq!(paths.all_paths_to(&3), vec![vec![0, 1, 3], vec![0, 2, 3]]);
+        assert_eq!(paths.nodes_on_paths([&3]), HashSet::from([0, 1, 2, 3]));
+        assert_eq!(paths.path_to(&4), Some(vec![0, 1, 3, 4]));
+    }
+
+    #[test]
+    fn test_weighted_searches() {
+        // The direct edge is dearer than the detour through 1.
+        let weighted = |node: &u32| match node {
+            0 => vec![(1, 1), (2, 10)],
+            1 => vec![(2, 2)],
+            _ => vec![],
+        };
+        assert_eq!(dijkstra(0, weighted, |&n| n == 2), Some((vec![0, 1, 2], 3)));
+        assert_eq!(astar(0, weighted, |&n| 2 - n.min(2), |&n| n == 2), Some((vec![0, 1, 2], 3)));
+        let all = dijkstra_all(0, weighted);
+        assert_eq!(all.cost(&2), Some(3));
+        assert_eq!(all.predecessors[&2], vec![1]);
+    }
+}